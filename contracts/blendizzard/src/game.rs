@@ -1,11 +1,17 @@
-use soroban_sdk::{Address, Env, IntoVal as _, vec};
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{contracttype, Address, Env, IntoVal as _, Map, Vec, vec};
 
 use crate::errors::Error;
 use crate::events::{emit_game_ended, emit_game_started};
 use crate::faction::lock_epoch_faction;
 use crate::faction_points::{initialize_epoch_fp, lock_fp};
 use crate::storage;
-use crate::types::GameSession;
+use crate::storage::PotSession;
+use crate::types::{EpochInfo, GameSession};
+
+/// Denominator for `end_game_multi` payout shares, expressed in basis
+/// points - a share of 10_000 is 100% of the pot.
+pub(crate) const PAYOUT_DENOM_BPS: i128 = 10_000;
 
 // ============================================================================
 // Game Registry
@@ -139,6 +145,9 @@ pub(crate) fn start_game(
     // Get current epoch
     let current_epoch = storage::get_current_epoch(env);
 
+    // Reject new sessions once the epoch has been frozen ahead of finalization
+    storage::require_epoch_open(env, current_epoch)?;
+
     // Initialize faction points for each player if this is their first game
     // This also locks in their total available FP for the epoch
     initialize_player_epoch(env, player1, current_epoch)?;
@@ -166,6 +175,10 @@ pub(crate) fn start_game(
     // Save session
     storage::set_session(env, session_id, &session);
 
+    // Record when this session's wagers were locked, so `resolve_expired_game`
+    // can later tell whether it's been abandoned past the configured timeout.
+    storage::set_game_start_time(env, session_id, env.ledger().timestamp());
+
     // Get epoch player data for event emission
     let p1_epoch_data =
         storage::get_epoch_player(env, current_epoch, player1).ok_or(Error::PlayerNotFound)?;
@@ -196,6 +209,11 @@ pub(crate) fn start_game(
 /// Each game is responsible for implementing its own verification mechanism
 /// (multi-sig oracle, ZK proofs, etc.) before calling this function.
 ///
+/// If a dispute window is configured (`set_dispute_window`), the outcome is
+/// only recorded as provisional here - see `dispute_outcome`/`settle_game`
+/// for how it's actually applied or overturned. With the default zero
+/// window, the outcome is still applied immediately, exactly as before.
+///
 /// # Arguments
 /// * `env` - Contract environment
 /// * `session_id` - The unique session identifier
@@ -205,6 +223,7 @@ pub(crate) fn start_game(
 /// * `SessionNotFound` - If session doesn't exist
 /// * `InvalidSessionState` - If session is not in Pending state
 /// * `GameExpired` - If game is from a previous epoch
+/// * `EpochNotOpen` - If the current epoch has been frozen/finalized
 pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
     // Get session
     let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
@@ -213,8 +232,12 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
     // Only the whitelisted game contract should be able to submit outcomes
     session.game_id.require_auth();
 
-    // Validate session state (game must not be completed yet)
-    if session.player1_won.is_some() {
+    // Validate session state (game must not be completed, already disputable,
+    // or already closed out as abandoned via `resolve_expired_game`)
+    if session.player1_won.is_some()
+        || storage::has_dispute(env, session_id)
+        || storage::has_expired_game(env, session_id)
+    {
         return Err(Error::InvalidSessionState);
     }
 
@@ -225,6 +248,247 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
         return Err(Error::GameExpired);
     }
 
+    // Reject settlement once the epoch has been frozen, even for a session
+    // that started while it was still Open - this is the race `freeze_epoch`
+    // closes: a game that ends after the freeze no longer slips through
+    // unaccounted-for as a silent GameExpired.
+    storage::require_epoch_open(env, current_epoch)?;
+
+    let dispute_window = storage::get_dispute_window_ledgers(env);
+    if dispute_window == 0 {
+        // Zero-window default: keep behaving like instant settlement.
+        return apply_game_outcome(env, session_id, &mut session, player1_won);
+    }
+
+    // Record the outcome as provisional rather than crediting it yet -
+    // `dispute_outcome` can still overturn it before `challenge_deadline`.
+    let challenge_deadline = env
+        .ledger()
+        .sequence()
+        .checked_add(dispute_window)
+        .ok_or(Error::OverflowError)?;
+    storage::set_dispute(
+        env,
+        session_id,
+        &storage::Dispute {
+            player1_won,
+            challenge_deadline,
+        },
+    );
+
+    crate::events::emit_game_disputable(env, &session.game_id, session_id, challenge_deadline);
+
+    Ok(())
+}
+
+/// Finalize a session whose dispute window has passed undisputed, applying
+/// its provisional outcome for good via `apply_game_outcome`
+///
+/// # Errors
+/// * `SessionNotFound` - If no dispute is pending for this session
+/// * `DeadlineNotReached` - If `challenge_deadline` hasn't passed yet
+/// * `EpochNotOpen` - If the session's epoch has been frozen/finalized since
+pub(crate) fn settle_game(env: &Env, session_id: u32) -> Result<(), Error> {
+    let dispute = storage::get_dispute(env, session_id).ok_or(Error::SessionNotFound)?;
+    if env.ledger().sequence() < dispute.challenge_deadline {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    // Same guard `end_game` applies before crediting an outcome directly -
+    // a dispute window can easily outlast `freeze_epoch`/`finalize_epoch`,
+    // and a provisional outcome settling into an already-finalized epoch
+    // would dilute every earlier claimant's frozen reward share.
+    storage::require_epoch_open(env, session.epoch_id)?;
+
+    storage::remove_dispute(env, session_id);
+
+    apply_game_outcome(env, session_id, &mut session, dispute.player1_won)
+}
+
+/// Dispute a provisional game outcome before `challenge_deadline`
+///
+/// The admin may dispute for free (no collateral required) and is trusted
+/// to adjudicate on the spot: the dispute takes effect immediately, exactly
+/// like before - both players' locked wagers refund and the game contract is
+/// de-whitelisted.
+///
+/// Anyone else is a bonded challenger, not an adjudicator. They must post
+/// `collateral` FP (locked the same way a wager is), but nothing is
+/// refunded, rewarded, or de-whitelisted yet - `correct_result` is just
+/// their claim. The session stays disputed until the admin adjudicates it
+/// via `resolve_dispute`, which decides whether the challenger's collateral
+/// is returned (plus a reward) or slashed.
+///
+/// # Errors
+/// * `SessionNotFound` - If no dispute is pending for this session
+/// * `DisputeWindowClosed` - If `challenge_deadline` has already passed
+/// * `InvalidSessionState` - If a bonded challenge is already pending
+/// * `InvalidAmount` - If a non-admin challenger posts collateral <= 0
+/// * `InsufficientFactionPoints` - If the challenger can't afford `collateral`
+pub(crate) fn dispute_outcome(
+    env: &Env,
+    session_id: u32,
+    correct_result: bool,
+    caller: &Address,
+    collateral: i128,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    let dispute = storage::get_dispute(env, session_id).ok_or(Error::SessionNotFound)?;
+    if env.ledger().sequence() >= dispute.challenge_deadline {
+        return Err(Error::DisputeWindowClosed);
+    }
+    if storage::has_challenge(env, session_id) {
+        return Err(Error::InvalidSessionState);
+    }
+
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    let current_epoch = storage::get_current_epoch(env);
+
+    let admin = storage::get_admin(env);
+    let is_admin = caller == &admin;
+
+    if is_admin {
+        void_disputed_session(env, &session, &dispute, None, current_epoch)?;
+        storage::remove_dispute(env, session_id);
+    } else {
+        if collateral <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        lock_fp(env, caller, collateral, current_epoch)?;
+
+        storage::set_challenge(
+            env,
+            session_id,
+            &storage::Challenge {
+                challenger: caller.clone(),
+                collateral,
+                correct_result,
+            },
+        );
+    }
+
+    crate::events::emit_game_disputed(env, &session.game_id, session_id, caller, correct_result);
+
+    Ok(())
+}
+
+/// Adjudicate a pending bonded challenge against a provisional outcome
+///
+/// Admin-only. A non-admin `dispute_outcome` call no longer resolves
+/// anything by itself (see above) - this is what actually settles it.
+///
+/// If `uphold` is true, the challenger was right: both wagers refund, the
+/// game contract is de-whitelisted, and the challenger is paid back their
+/// `collateral` plus the disputed winner's forfeited wager - the same
+/// outcome an admin dispute produces immediately. If `uphold` is false, the
+/// challenger was wrong: their bonded `collateral` is slashed (it's simply
+/// never enqueued for unlock, unlike a wager or a correct challenger's
+/// collateral) and the session's original provisional outcome is applied
+/// via `apply_game_outcome`, same as an undisputed `settle_game` would have.
+///
+/// # Errors
+/// * `SessionNotFound` - If no bonded challenge is pending for this session
+/// * `EpochNotOpen` - If the session's epoch has been frozen/finalized since
+pub(crate) fn resolve_dispute(env: &Env, session_id: u32, uphold: bool) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let challenge = storage::get_challenge(env, session_id).ok_or(Error::SessionNotFound)?;
+    let dispute = storage::get_dispute(env, session_id).ok_or(Error::SessionNotFound)?;
+    let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    let current_epoch = storage::get_current_epoch(env);
+
+    storage::remove_challenge(env, session_id);
+    storage::remove_dispute(env, session_id);
+
+    if uphold {
+        void_disputed_session(
+            env,
+            &session,
+            &dispute,
+            Some((challenge.challenger.clone(), challenge.collateral)),
+            current_epoch,
+        )?;
+    } else {
+        storage::require_epoch_open(env, session.epoch_id)?;
+        apply_game_outcome(env, session_id, &mut session, dispute.player1_won)?;
+    }
+
+    crate::events::emit_dispute_resolved(env, session_id, uphold);
+
+    Ok(())
+}
+
+/// Refund both players' locked wagers and de-whitelist the disputed game
+/// contract - the shared "the provisional outcome can't be trusted" path for
+/// both an admin's immediate dispute and a `resolve_dispute(uphold: true)`.
+///
+/// `challenger_reward`, when present, pays that address the disputed
+/// winner's forfeited wager on top of their own bonded collateral; an admin
+/// dispute passes `None` since the admin posts no collateral and needs no
+/// incentive.
+fn void_disputed_session(
+    env: &Env,
+    session: &storage::GameSession,
+    dispute: &storage::Dispute,
+    challenger_reward: Option<(Address, i128)>,
+    current_epoch: u32,
+) -> Result<(), Error> {
+    // Refund both players' locked wagers - no FP was ever credited to
+    // either side by the dispute-window flow, so this just undoes the
+    // `lock_fp` from `start_game`.
+    enqueue_unlock(env, &session.player1, session.player1_wager, current_epoch);
+    enqueue_unlock(env, &session.player2, session.player2_wager, current_epoch);
+
+    storage::remove_game_from_whitelist(env, &session.game_id);
+
+    if let Some((challenger, collateral)) = challenger_reward {
+        let disputed_winner_wager = if dispute.player1_won {
+            session.player1_wager
+        } else {
+            session.player2_wager
+        };
+        let payout = collateral
+            .checked_add(disputed_winner_wager)
+            .ok_or(Error::OverflowError)?;
+        enqueue_unlock(env, &challenger, payout, current_epoch);
+    }
+
+    Ok(())
+}
+
+/// Configure the dispute window (in ledgers) new `end_game` calls use
+///
+/// A window of 0 (the default) keeps the old instant-settlement behavior:
+/// `end_game` credits the outcome immediately with nothing to dispute.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn set_dispute_window(env: &Env, window_ledgers: u32) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::set_dispute_window_ledgers(env, window_ledgers);
+
+    Ok(())
+}
+
+/// Apply a game's final outcome: credit the winner's FP contribution
+/// (including any backing FP), update faction standings, mark the session
+/// complete, and emit `GameEnded`. Called either immediately by `end_game`
+/// when no dispute window is configured, or by `settle_game` once the
+/// window has passed undisputed.
+fn apply_game_outcome(
+    env: &Env,
+    session_id: u32,
+    session: &mut GameSession,
+    player1_won: bool,
+) -> Result<(), Error> {
+    let current_epoch = storage::get_current_epoch(env);
+
     // Determine winner and loser
     let (winner, loser, winner_wager, _loser_wager) = if player1_won {
         // Player1 won
@@ -252,11 +516,24 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
     let mut winner_epoch =
         storage::get_epoch_player(env, current_epoch, winner).ok_or(Error::PlayerNotFound)?;
 
-    // Only winner's wager contributes to faction standings
-    // Note: Wager is already in FP units with multipliers applied
+    // The winner's wager plus any FP currently backing them (via delegation)
+    // both contribute to their standings - a backer's FP counts exactly as
+    // if the winner had wagered it themself. See `total_backing_for` and
+    // `rewards::split_reward_with_backers` for the payout side of this.
+    let backing = total_backing_for(env, current_epoch, winner);
+    let total_contribution = winner_wager
+        .checked_add(backing)
+        .ok_or(Error::OverflowError)?;
+
+    // Skim the protocol commission off the pot before it counts towards the
+    // winner's standing - see `skim_commission`.
+    let distributable = skim_commission(env, total_contribution)?;
+
+    accrue_player_weighted_fp(env, current_epoch, winner)?;
+
     winner_epoch.total_fp_contributed = winner_epoch
         .total_fp_contributed
-        .checked_add(winner_wager)
+        .checked_add(distributable)
         .ok_or(Error::OverflowError)?;
 
     // Save winner's updated data
@@ -264,10 +541,10 @@ pub(crate) fn end_game(env: &Env, session_id: u32, player1_won: bool) -> Result<
 
     // Update session (marking it as completed)
     session.player1_won = Some(player1_won);
-    storage::set_session(env, session_id, &session);
+    storage::set_session(env, session_id, session);
 
-    // Update faction standings (only winner's wager contributes)
-    update_faction_standings(env, winner, winner_wager, current_epoch)?;
+    // Update faction standings (winner's distributable contribution, net of commission)
+    update_faction_standings(env, winner, distributable, current_epoch)?;
 
     // Emit event (only winner's wager counts as contribution)
     emit_game_ended(
@@ -339,6 +616,97 @@ fn initialize_player_epoch(env: &Env, player: &Address, current_epoch: u32) -> R
     Ok(())
 }
 
+// ============================================================================
+// Time-Weighted FP Accrual
+// ============================================================================
+// A player who dumps a huge FP contribution in an epoch's final seconds
+// would otherwise capture the same reward share as one who held that FP all
+// epoch, diluting everyone who contributed earlier. Every time a player's or
+// a faction's FP level is about to change, the level it held *before* the
+// change is integrated over the time that's elapsed since the last touch
+// (`weighted_fp += level_before_change * (now - last_update_t)`) before the
+// change is applied - so a contribution only starts earning weight from the
+// moment it lands, and late FP simply hasn't had time to accumulate much.
+
+/// A level integrated over time, brought forward to whenever it was last touched
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WeightedFp {
+    /// The level, integrated over time up through `last_update_t`
+    pub weighted_fp: i128,
+    /// Ledger timestamp the accumulator was last brought forward to
+    pub last_update_t: u64,
+}
+
+/// Bring a time-weighted accumulator forward to `now`, integrating
+/// `level_before_change` over the time elapsed since it was last touched
+fn accrue_weighted(
+    now: u64,
+    level_before_change: i128,
+    existing: Option<WeightedFp>,
+    fallback_last_update_t: u64,
+) -> Result<WeightedFp, Error> {
+    let last_update_t = existing.map(|w| w.last_update_t).unwrap_or(fallback_last_update_t);
+    let weighted_so_far = existing.map(|w| w.weighted_fp).unwrap_or(0);
+
+    let elapsed = now.checked_sub(last_update_t).ok_or(Error::OverflowError)?;
+    let delta = level_before_change
+        .checked_mul(elapsed as i128)
+        .ok_or(Error::OverflowError)?;
+
+    Ok(WeightedFp {
+        weighted_fp: weighted_so_far.checked_add(delta).ok_or(Error::OverflowError)?,
+        last_update_t: now,
+    })
+}
+
+/// Bring `player`'s time-weighted FP accumulator forward, integrating their
+/// FP level as it stands right now (before the caller applies whatever
+/// change prompted this call)
+///
+/// Must be called before `total_fp_contributed` is mutated in storage -
+/// the accumulator needs the level that was in effect up to this moment,
+/// not the new one.
+fn accrue_player_weighted_fp(env: &Env, epoch: u32, player: &Address) -> Result<(), Error> {
+    let epoch_player = storage::get_epoch_player(env, epoch, player).ok_or(Error::PlayerNotFound)?;
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+
+    let updated = accrue_weighted(
+        env.ledger().timestamp(),
+        epoch_player.total_fp_contributed,
+        storage::get_player_weighted_fp(env, epoch, player),
+        epoch_info.start_time,
+    )?;
+    storage::set_player_weighted_fp(env, epoch, player, &updated);
+
+    Ok(())
+}
+
+/// Bring `faction`'s time-weighted total FP accumulator forward, integrating
+/// `current_standing` (the faction's live `faction_standings` level, read by
+/// the caller before it changes) up to now
+///
+/// Must be called before `faction_standings` is mutated in storage, with the
+/// pre-mutation standing - same ordering requirement as
+/// `accrue_player_weighted_fp`.
+fn accrue_faction_weighted_fp(
+    env: &Env,
+    epoch: u32,
+    faction: u32,
+    current_standing: i128,
+    epoch_start_time: u64,
+) -> Result<(), Error> {
+    let updated = accrue_weighted(
+        env.ledger().timestamp(),
+        current_standing,
+        storage::get_faction_weighted_fp(env, epoch, faction),
+        epoch_start_time,
+    )?;
+    storage::set_faction_weighted_fp(env, epoch, faction, &updated);
+
+    Ok(())
+}
+
 /// Update faction standings with the winner's FP contribution
 fn update_faction_standings(
     env: &Env,
@@ -359,6 +727,9 @@ fn update_faction_standings(
 
     // Update faction standings
     let current_standing = epoch_info.faction_standings.get(faction).unwrap_or(0);
+
+    accrue_faction_weighted_fp(env, current_epoch, faction, current_standing, epoch_info.start_time)?;
+
     let new_standing = current_standing
         .checked_add(fp_amount)
         .ok_or(Error::OverflowError)?;
@@ -370,3 +741,900 @@ fn update_faction_standings(
 
     Ok(())
 }
+
+// ============================================================================
+// FP Delegation (Backing)
+// ============================================================================
+// Lets a player delegate part of their epoch FP to back another player of
+// the same faction, Substrate-staking-style (nominator -> validator). The
+// backed player's wins pull in their backers' FP via `total_backing_for`
+// above; reward-time payout of the backers' pro-rata share lives in
+// `crate::rewards::split_reward_with_backers`.
+
+/// Delegate `amount` FP from `backer` to `target` for the current epoch
+///
+/// `target` must already have locked in the same faction as `backer`
+/// (`EpochPlayer.epoch_faction`). `amount` is locked out of `backer`'s
+/// available FP exactly like a wager, via `faction_points::lock_fp`, so it
+/// can't also be spent in the backer's own games while it's backing someone
+/// else. A backer may only have one active delegation per epoch.
+///
+/// # Errors
+/// * `InvalidAmount` - If `amount` <= 0
+/// * `PlayerNotFound` - If `backer` or `target` have no epoch data yet
+/// * `FactionAlreadyLocked` - If `backer` or `target` haven't selected a faction yet
+/// * `FactionMismatch` - If `backer` and `target` aren't in the same faction
+/// * `DelegationAlreadyExists` - If `backer` already has an active delegation this epoch
+/// * `InsufficientFactionPoints` - If `backer` doesn't have `amount` available
+pub(crate) fn delegate_fp(env: &Env, backer: &Address, target: &Address, amount: i128) -> Result<(), Error> {
+    backer.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+
+    if storage::has_delegation(env, current_epoch, backer) {
+        return Err(Error::DelegationAlreadyExists);
+    }
+
+    let backer_epoch =
+        storage::get_epoch_player(env, current_epoch, backer).ok_or(Error::PlayerNotFound)?;
+    let target_epoch =
+        storage::get_epoch_player(env, current_epoch, target).ok_or(Error::PlayerNotFound)?;
+
+    let backer_faction = backer_epoch
+        .epoch_faction
+        .ok_or(Error::FactionAlreadyLocked)?;
+    let target_faction = target_epoch
+        .epoch_faction
+        .ok_or(Error::FactionAlreadyLocked)?;
+    if backer_faction != target_faction {
+        return Err(Error::FactionMismatch);
+    }
+
+    lock_fp(env, backer, amount, current_epoch)?;
+
+    storage::set_delegation(
+        env,
+        current_epoch,
+        backer,
+        &storage::Delegation {
+            target: target.clone(),
+            amount,
+        },
+    );
+    add_backer(env, current_epoch, target, backer);
+
+    Ok(())
+}
+
+/// Undelegate: release `backer`'s locked FP for the current epoch. The FP
+/// isn't immediately spendable again - it's enqueued via `enqueue_unlock` and
+/// becomes available once `withdraw_unlocked` is called after the configured
+/// unbonding period passes, same as any other FP release.
+///
+/// # Errors
+/// * `DelegationNotFound` - If `backer` has no active delegation this epoch
+pub(crate) fn undelegate_fp(env: &Env, backer: &Address) -> Result<(), Error> {
+    backer.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    let delegation =
+        storage::get_delegation(env, current_epoch, backer).ok_or(Error::DelegationNotFound)?;
+
+    enqueue_unlock(env, backer, delegation.amount, current_epoch);
+    storage::remove_delegation(env, current_epoch, backer);
+    remove_backer(env, current_epoch, &delegation.target, backer);
+
+    Ok(())
+}
+
+/// Every backer currently delegating to `target` for `epoch`
+pub(crate) fn get_backers(env: &Env, epoch: u32, target: &Address) -> soroban_sdk::Vec<Address> {
+    storage::get_backers(env, epoch, target)
+}
+
+/// Sum of all FP currently backing `target` for `epoch`
+pub(crate) fn total_backing_for(env: &Env, epoch: u32, target: &Address) -> i128 {
+    let backers = storage::get_backers(env, epoch, target);
+    let mut total: i128 = 0;
+    for backer in backers.iter() {
+        if let Some(delegation) = storage::get_delegation(env, epoch, &backer) {
+            total += delegation.amount;
+        }
+    }
+    total
+}
+
+fn add_backer(env: &Env, epoch: u32, target: &Address, backer: &Address) {
+    let mut backers = storage::get_backers(env, epoch, target);
+    backers.push_back(backer.clone());
+    storage::set_backers(env, epoch, target, &backers);
+}
+
+fn remove_backer(env: &Env, epoch: u32, target: &Address, backer: &Address) {
+    let backers = storage::get_backers(env, epoch, target);
+    let mut without: soroban_sdk::Vec<Address> = soroban_sdk::Vec::new(env);
+    for existing in backers.iter() {
+        if &existing != backer {
+            without.push_back(existing);
+        }
+    }
+    storage::set_backers(env, epoch, target, &without);
+}
+
+// ============================================================================
+// Faction Delegation (Non-Player Backing)
+// ============================================================================
+// Lets an FP holder back an entire faction directly, without entering any
+// head-to-head game. Unlike `delegate_fp`/`undelegate_fp` above, which back
+// a specific target player and only pay out once that player wins, this
+// credits `faction_standings` and the delegator's own `total_fp_contributed`
+// immediately, exactly as if the delegator had won a game for that amount -
+// so they're eligible for a proportional reward share alongside active
+// players without ever playing one.
+
+/// Delegate `amount` FP from `delegator` directly to `faction`'s standing
+/// for the current epoch, without playing any game
+///
+/// `delegator` must already have selected `faction` (`EpochPlayer.epoch_faction`,
+/// set via `select_faction`). `amount` is locked out of `delegator`'s
+/// available FP exactly like a wager, via `faction_points::lock_fp`, so it
+/// can't also be spent in a game while it's backing the faction. A
+/// delegator may only have one active faction delegation per epoch.
+///
+/// # Errors
+/// * `InvalidAmount` - If `amount` <= 0
+/// * `EpochNotOpen` - If the current epoch has been frozen/finalized
+/// * `PlayerNotFound` - If `delegator` has no epoch data yet
+/// * `FactionAlreadyLocked` - If `delegator` hasn't selected a faction yet
+/// * `FactionMismatch` - If `faction` doesn't match `delegator`'s selected faction
+/// * `DelegationAlreadyExists` - If `delegator` already has an active faction delegation this epoch
+/// * `InsufficientFactionPoints` - If `delegator` doesn't have `amount` available
+pub(crate) fn delegate_fp_to_faction(
+    env: &Env,
+    delegator: &Address,
+    faction: u32,
+    amount: i128,
+) -> Result<(), Error> {
+    delegator.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    storage::require_epoch_open(env, current_epoch)?;
+
+    if storage::has_faction_delegation(env, current_epoch, delegator) {
+        return Err(Error::DelegationAlreadyExists);
+    }
+
+    let delegator_epoch =
+        storage::get_epoch_player(env, current_epoch, delegator).ok_or(Error::PlayerNotFound)?;
+    let delegator_faction = delegator_epoch
+        .epoch_faction
+        .ok_or(Error::FactionAlreadyLocked)?;
+    if delegator_faction != faction {
+        return Err(Error::FactionMismatch);
+    }
+
+    lock_fp(env, delegator, amount, current_epoch)?;
+
+    // Credit the delegation as a contribution exactly like a game win -
+    // `update_faction_standings` re-reads this from storage, so save it first.
+    let mut delegator_epoch =
+        storage::get_epoch_player(env, current_epoch, delegator).ok_or(Error::PlayerNotFound)?;
+
+    accrue_player_weighted_fp(env, current_epoch, delegator)?;
+
+    delegator_epoch.total_fp_contributed = delegator_epoch
+        .total_fp_contributed
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_player(env, current_epoch, delegator, &delegator_epoch);
+
+    storage::set_faction_delegation(env, current_epoch, delegator, amount);
+
+    update_faction_standings(env, delegator, amount, current_epoch)?;
+
+    Ok(())
+}
+
+/// Undelegate: release `delegator`'s locked faction-delegation FP for the
+/// current epoch, reversing its contribution to both `faction_standings`
+/// and the delegator's `total_fp_contributed`. The FP isn't immediately
+/// spendable again - it's enqueued via `enqueue_unlock`, same as any other
+/// FP release.
+///
+/// # Errors
+/// * `EpochNotOpen` - If the current epoch has been frozen/finalized
+/// * `DelegationNotFound` - If `delegator` has no active faction delegation this epoch
+pub(crate) fn undelegate_fp_from_faction(env: &Env, delegator: &Address) -> Result<(), Error> {
+    delegator.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    storage::require_epoch_open(env, current_epoch)?;
+
+    withdraw_faction_delegation(env, delegator, current_epoch)
+}
+
+/// Admin emergency path: force-undelegate `delegator`'s faction delegation
+/// for the current epoch, bypassing the `EpochNotOpen` check that blocks
+/// `undelegate_fp_from_faction` once an epoch is frozen or finalized -
+/// for emergency migrations where the FP needs to move regardless of epoch
+/// state.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `DelegationNotFound` - If `delegator` has no active faction delegation this epoch
+pub(crate) fn force_undelegate(env: &Env, delegator: &Address) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    withdraw_faction_delegation(env, delegator, current_epoch)
+}
+
+/// Shared undo path for both `undelegate_fp_from_faction` and
+/// `force_undelegate`: reverse the delegation's contribution to
+/// `faction_standings` and `total_fp_contributed`, enqueue the FP for
+/// release, and remove the delegation record.
+fn withdraw_faction_delegation(env: &Env, delegator: &Address, current_epoch: u32) -> Result<(), Error> {
+    let amount = storage::get_faction_delegation(env, current_epoch, delegator)
+        .ok_or(Error::DelegationNotFound)?;
+
+    let mut delegator_epoch =
+        storage::get_epoch_player(env, current_epoch, delegator).ok_or(Error::PlayerNotFound)?;
+    let faction = delegator_epoch
+        .epoch_faction
+        .ok_or(Error::FactionAlreadyLocked)?;
+
+    accrue_player_weighted_fp(env, current_epoch, delegator)?;
+
+    delegator_epoch.total_fp_contributed = delegator_epoch
+        .total_fp_contributed
+        .checked_sub(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_player(env, current_epoch, delegator, &delegator_epoch);
+
+    let mut epoch_info = storage::get_epoch(env, current_epoch).ok_or(Error::EpochNotFinalized)?;
+    let current_standing = epoch_info.faction_standings.get(faction).unwrap_or(0);
+
+    accrue_faction_weighted_fp(env, current_epoch, faction, current_standing, epoch_info.start_time)?;
+
+    epoch_info
+        .faction_standings
+        .set(faction, current_standing.checked_sub(amount).ok_or(Error::OverflowError)?);
+    storage::set_epoch(env, current_epoch, &epoch_info);
+
+    enqueue_unlock(env, delegator, amount, current_epoch);
+    storage::remove_faction_delegation(env, current_epoch, delegator);
+
+    Ok(())
+}
+
+// ============================================================================
+// Epoch Lifecycle
+// ============================================================================
+// Borrowed from Solana's bank lifecycle (open -> frozen -> rooted): an epoch
+// starts Open, `freeze_epoch` stops new sessions and rejects any in-flight
+// session that settles after the freeze, and `finalize_epoch` computes and
+// locks the epoch's winning faction before enabling reward claims. Epochs
+// that never call `freeze_epoch`/`finalize_epoch` stay `Open` forever and
+// keep behaving exactly like the old instant-settlement flow.
+
+/// Freeze the current epoch
+///
+/// `start_game` and `end_game` reject the epoch from this point on, leaving
+/// room for whatever sessions are still settling to close out (or time out)
+/// before `finalize_epoch` locks the final standings.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `EpochNotOpen` - If the current epoch isn't Open
+pub(crate) fn freeze_epoch(env: &Env) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    storage::require_epoch_open(env, current_epoch)?;
+
+    storage::set_epoch_state(env, current_epoch, storage::EpochState::Frozen);
+    crate::events::emit_epoch_frozen(env, current_epoch);
+
+    Ok(())
+}
+
+/// Finalize the current epoch
+///
+/// Computes the winning faction from `faction_standings` (the highest
+/// standing wins; ties resolve to the lowest faction id), locks that result
+/// into `EpochInfo.winning_faction`/`is_finalized`, and transitions the
+/// epoch to `Finalized`. Only after this does `claim_epoch_reward` become
+/// callable for the epoch.
+///
+/// The winning faction's total FP standing is also frozen into
+/// `storage::FrozenWinningFp` at this exact moment - `claim_epoch_reward`
+/// reads that frozen total instead of `faction_standings` live, so a
+/// contribution that somehow still lands in this epoch after finalization
+/// (a stale dispute settling, say) can't dilute every earlier claimant's
+/// share by inflating the denominator out from under them. `EpochState::Frozen`/
+/// `Finalized` already close off the honest paths that credit standings
+/// (`apply_game_outcome`, `delegate_fp_to_faction`) via `require_epoch_open` -
+/// this freeze is the backstop for the rest.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `EpochNotFrozen` - If the current epoch hasn't been frozen yet
+/// * `EpochNotFinalized` - If no `EpochInfo` has been recorded for the current epoch
+pub(crate) fn finalize_epoch(env: &Env) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    if storage::get_epoch_state(env, current_epoch) != storage::EpochState::Frozen {
+        return Err(Error::EpochNotFrozen);
+    }
+
+    let mut epoch_info = storage::get_epoch(env, current_epoch).ok_or(Error::EpochNotFinalized)?;
+
+    let mut winning_faction = 0u32;
+    let mut highest_standing = i128::MIN;
+    for (faction, standing) in epoch_info.faction_standings.iter() {
+        if standing > highest_standing {
+            highest_standing = standing;
+            winning_faction = faction;
+        }
+    }
+
+    epoch_info.winning_faction = Some(winning_faction);
+    epoch_info.is_finalized = true;
+    storage::set_epoch(env, current_epoch, &epoch_info);
+
+    storage::set_epoch_state(env, current_epoch, storage::EpochState::Finalized);
+    storage::set_frozen_winning_fp(env, current_epoch, highest_standing);
+
+    // Bring the winning faction's time-weighted accumulator forward to
+    // `end_time` and freeze it - same rationale as `FrozenWinningFp` above,
+    // but for the time-weighted denominator `claim_epoch_reward` prefers when
+    // it's available. For a faction that never accrued weighted data (nothing
+    // ever called the mutation paths that touch `accrue_faction_weighted_fp`)
+    // this freezes `highest_standing` integrated over the whole epoch; its
+    // players have no weighted accumulator either, so `claim_epoch_reward`
+    // falls back to the unweighted totals for them unchanged.
+    let frozen_weighted = accrue_weighted(
+        epoch_info.end_time,
+        highest_standing,
+        storage::get_faction_weighted_fp(env, current_epoch, winning_faction),
+        epoch_info.start_time,
+    )?;
+    storage::set_frozen_winning_weighted_fp(env, current_epoch, frozen_weighted.weighted_fp);
+
+    crate::events::emit_epoch_finalized(env, current_epoch, winning_faction);
+
+    Ok(())
+}
+
+// ============================================================================
+// Epoch Cycling
+// ============================================================================
+// A naive epoch cycle - finalize, swap accumulated BLND yield for USDC, fund
+// the reward pool, and open the next epoch - all in one transaction risks
+// exceeding Soroban's resource limits as player/faction counts grow, and a
+// flaky swap aborts finalization along with it. `cycle_epoch` instead
+// advances at most one bounded phase per call, recording progress in
+// `storage::EpochCycleStatus` so a retry resumes exactly where the last call
+// left off rather than re-running completed work.
+
+/// Advance the current epoch's cycle by one bounded phase
+///
+/// Phases run in order and persist between calls:
+/// * `Active` -> freezes and finalizes the epoch (reusing `freeze_epoch`/
+///   `finalize_epoch`), advances to `Finalizing`
+/// * `Finalizing` -> swaps the contract's accumulated BLND yield for USDC via
+///   `crate::vault::swap_blnd_to_usdc`, adding it to `reward_pool`, advances
+///   to `Swapping`
+/// * `Swapping` -> advances to `Distributing`. Per-claimant payouts are
+///   already a lazy pull (`claim_epoch_reward`) rather than a push the
+///   contract loops over, so there's no per-player work to chunk here; this
+///   phase exists so keepers have a distinct, observable step confirming the
+///   pool is funded before the epoch opens for claims
+/// * `Distributing` -> opens the next epoch (`CurrentEpoch` advances,
+///   `EpochCycleStatus` resets to `Active` for it)
+///
+/// Call repeatedly until the next epoch opens. Each call only needs the
+/// admin's authorization for the one phase it performs.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `EpochNotOpen` / `EpochNotFrozen` / `EpochNotFinalized` - Surfaced from
+///   the underlying `freeze_epoch`/`finalize_epoch` calls during the
+///   `Active` phase
+/// * `OverflowError` - If swap proceeds or the epoch number overflow
+pub(crate) fn cycle_epoch(env: &Env) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+
+    match storage::get_epoch_cycle_status(env, current_epoch) {
+        storage::EpochCycleStatus::Active => {
+            if storage::get_epoch_state(env, current_epoch) == storage::EpochState::Open {
+                freeze_epoch(env)?;
+            }
+            finalize_epoch(env)?;
+            storage::set_epoch_cycle_status(env, current_epoch, storage::EpochCycleStatus::Finalizing);
+            Ok(())
+        }
+        storage::EpochCycleStatus::Finalizing => {
+            let usdc_from_swap = crate::vault::swap_blnd_to_usdc(env)?;
+            let mut epoch_info = storage::get_epoch(env, current_epoch).ok_or(Error::EpochNotFinalized)?;
+            epoch_info.reward_pool = epoch_info
+                .reward_pool
+                .checked_add(usdc_from_swap)
+                .ok_or(Error::OverflowError)?;
+            storage::set_epoch(env, current_epoch, &epoch_info);
+            storage::set_epoch_cycle_status(env, current_epoch, storage::EpochCycleStatus::Swapping);
+            Ok(())
+        }
+        storage::EpochCycleStatus::Swapping => {
+            storage::set_epoch_cycle_status(env, current_epoch, storage::EpochCycleStatus::Distributing);
+            Ok(())
+        }
+        storage::EpochCycleStatus::Distributing => {
+            let next_epoch = current_epoch.checked_add(1).ok_or(Error::OverflowError)?;
+            let config = storage::get_config(env);
+            let start_time = env.ledger().timestamp();
+            let end_time = start_time
+                .checked_add(config.epoch_duration)
+                .ok_or(Error::OverflowError)?;
+            let next_epoch_info = EpochInfo {
+                start_time,
+                end_time,
+                faction_standings: Map::new(env),
+                reward_pool: 0,
+                winning_faction: None,
+                is_finalized: false,
+            };
+            storage::set_epoch(env, next_epoch, &next_epoch_info);
+            storage::set_current_epoch(env, next_epoch);
+            storage::set_epoch_cycle_status(env, next_epoch, storage::EpochCycleStatus::Active);
+            crate::events::emit_epoch_cycled(env, current_epoch, next_epoch);
+            Ok(())
+        }
+    }
+}
+
+/// Get an epoch's current `cycle_epoch` phase, plus how many of the four
+/// known phases (`Active`, `Finalizing`, `Swapping`, `Distributing`) have
+/// already run - lets a keeper driving `cycle_epoch` to completion tell how
+/// much work is left without guessing from `EpochState` alone
+pub(crate) fn get_cycle_status(env: &Env, epoch: u32) -> (storage::EpochCycleStatus, u32) {
+    let phase = storage::get_epoch_cycle_status(env, epoch);
+    let phases_complete = match phase {
+        storage::EpochCycleStatus::Active => 0,
+        storage::EpochCycleStatus::Finalizing => 1,
+        storage::EpochCycleStatus::Swapping => 2,
+        storage::EpochCycleStatus::Distributing => 3,
+    };
+    (phase, phases_complete)
+}
+
+// ============================================================================
+// FP Unbonding
+// ============================================================================
+// Modeled on Substrate staking's `StakingLedger.unlocking` chunks: FP that's
+// released (a dispute refund, an undelegate, or a direct `unlock_fp` call for
+// unused locked FP) doesn't land back in `available_fp` immediately. It's
+// queued as an `(amount, available_epoch)` chunk and only becomes spendable
+// once `withdraw_unlocked` is called after `available_epoch` has passed.
+// Without this delay a player could lock FP in a game, get it released
+// mid-epoch, and immediately re-wager the same FP to manipulate faction
+// standings more than their true balance should allow.
+
+/// Release `amount` FP for `player`, unused or refunded from a locked wager,
+/// into their unbonding queue rather than crediting `available_fp` directly
+///
+/// # Errors
+/// * `InvalidAmount` - If `amount` <= 0
+pub(crate) fn unlock_fp(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    enqueue_unlock(env, player, amount, current_epoch);
+
+    Ok(())
+}
+
+/// Sweep `player`'s unbonding queue, crediting every chunk whose
+/// `available_epoch` has passed back into the current epoch's `available_fp`
+///
+/// # Errors
+/// * `PlayerNotFound` - If `player` has no epoch data for the current epoch
+pub(crate) fn withdraw_unlocked(env: &Env, player: &Address) -> Result<i128, Error> {
+    player.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    let chunks = storage::get_unlocking_chunks(env, player);
+
+    let mut matured: i128 = 0;
+    let mut remaining: soroban_sdk::Vec<(i128, u32)> = soroban_sdk::Vec::new(env);
+    for (chunk_amount, available_epoch) in chunks.iter() {
+        if available_epoch <= current_epoch {
+            matured = matured.checked_add(chunk_amount).ok_or(Error::OverflowError)?;
+        } else {
+            remaining.push_back((chunk_amount, available_epoch));
+        }
+    }
+
+    if matured == 0 {
+        return Ok(0);
+    }
+
+    storage::set_unlocking_chunks(env, player, &remaining);
+
+    let mut epoch_player =
+        storage::get_epoch_player(env, current_epoch, player).ok_or(Error::PlayerNotFound)?;
+    epoch_player.available_fp = epoch_player
+        .available_fp
+        .checked_add(matured)
+        .ok_or(Error::OverflowError)?;
+    storage::set_epoch_player(env, current_epoch, player, &epoch_player);
+
+    Ok(matured)
+}
+
+/// Configure the unbonding period (in epochs) newly-enqueued FP releases use
+///
+/// A period of 0 (the default) keeps the old instant-release behavior:
+/// every chunk is already matured the epoch it's enqueued in, so the very
+/// next `withdraw_unlocked` call sweeps it.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn set_unbonding_epochs(env: &Env, epochs: u32) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::set_unbonding_epochs(env, epochs);
+
+    Ok(())
+}
+
+/// Queue `amount` FP for `player`, released as of `released_epoch`, to
+/// become withdrawable once `released_epoch + unbonding_epochs` passes
+fn enqueue_unlock(env: &Env, player: &Address, amount: i128, released_epoch: u32) {
+    let unbonding_epochs = storage::get_unbonding_epochs(env);
+    let available_epoch = released_epoch.saturating_add(unbonding_epochs);
+
+    let mut chunks = storage::get_unlocking_chunks(env, player);
+    chunks.push_back((amount, available_epoch));
+    storage::set_unlocking_chunks(env, player, &chunks);
+}
+
+// ============================================================================
+// Abandoned Game Resolution
+// ============================================================================
+// `start_game` locks both players' wagers for the duration of a session, but
+// nothing ever forced the whitelisted game contract to actually call
+// `end_game` - a game contract that crashes, gets abandoned mid-match, or
+// simply never calls back leaves both wagers locked forever. This adds a
+// permissionless timeout: once `game_timeout` has elapsed since the session
+// started, anyone can call `resolve_expired_game` to refund both wagers with
+// no faction contribution and close the session out for good.
+
+/// Resolve an abandoned session once its timeout has elapsed, refunding both
+/// players' locked wagers with no faction contribution
+///
+/// Permissionless - anyone can trigger this once the deadline has passed, the
+/// same way anyone can call `settle_game` once a dispute window lapses. Both
+/// wagers go through the unbonding queue (`enqueue_unlock`) exactly like any
+/// other FP release, and the session is marked closed so the real game
+/// contract's `end_game` can never double-spend it afterward.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game` - Expected game contract for this session, checked against the
+///   session's recorded `game_id`
+/// * `session_id` - The unique session identifier
+///
+/// # Errors
+/// * `SessionNotFound` - If the session doesn't exist, or `game` doesn't match it
+/// * `InvalidSessionState` - If the session already ended, is disputed, or was already resolved
+/// * `GameTimeoutNotConfigured` - If no admin has ever called `set_game_timeout`
+/// * `DeadlineNotReached` - If `game_timeout` hasn't elapsed since the session started
+pub(crate) fn resolve_expired_game(env: &Env, game: &Address, session_id: u32) -> Result<(), Error> {
+    let session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    if &session.game_id != game {
+        return Err(Error::SessionNotFound);
+    }
+
+    if session.player1_won.is_some()
+        || storage::has_dispute(env, session_id)
+        || storage::has_expired_game(env, session_id)
+    {
+        return Err(Error::InvalidSessionState);
+    }
+
+    let timeout = storage::get_game_timeout(env);
+    if timeout == 0 {
+        return Err(Error::GameTimeoutNotConfigured);
+    }
+
+    let start_time = storage::get_game_start_time(env, session_id).ok_or(Error::SessionNotFound)?;
+    let deadline = start_time.checked_add(timeout).ok_or(Error::OverflowError)?;
+    if env.ledger().timestamp() <= deadline {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    enqueue_unlock(env, &session.player1, session.player1_wager, current_epoch);
+    enqueue_unlock(env, &session.player2, session.player2_wager, current_epoch);
+
+    storage::set_expired_game(env, session_id);
+
+    crate::events::emit_game_expired(env, &session.game_id, session_id);
+
+    Ok(())
+}
+
+/// Configure the game timeout (in seconds) `resolve_expired_game` uses
+///
+/// A timeout of 0 (the default) keeps `resolve_expired_game` disabled, so
+/// abandoned sessions stay locked exactly like before this existed.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn set_game_timeout(env: &Env, timeout_seconds: u64) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::set_game_timeout(env, timeout_seconds);
+
+    Ok(())
+}
+
+// ============================================================================
+// Protocol Commission
+// ============================================================================
+// `end_game`/`end_game_multi` skim a protocol fee off a game's pot before
+// the remaining, distributable amount flows into faction standings. The
+// skimmed FP accrues to the treasury as a claimable running total rather
+// than being burned along with the loser's wager.
+
+/// Configure the protocol commission (in basis points, `PAYOUT_DENOM_BPS` =
+/// 100%) skimmed from every game's pot at `end_game`/`end_game_multi` time
+///
+/// A commission of 0 (the default) keeps the old behavior where the full
+/// pot flows into faction standings with nothing skimmed.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `InvalidAmount` - If `bps` is greater than `PAYOUT_DENOM_BPS` (10_000)
+pub(crate) fn set_commission(env: &Env, bps: u32) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    if bps as i128 > PAYOUT_DENOM_BPS {
+        return Err(Error::InvalidAmount);
+    }
+
+    storage::set_game_commission_bps(env, bps);
+
+    Ok(())
+}
+
+/// Get the configured protocol commission, in basis points
+pub(crate) fn get_commission(env: &Env) -> u32 {
+    storage::get_game_commission_bps(env)
+}
+
+/// Skim the configured protocol commission off `pot`, crediting the fee to
+/// the treasury's claimable FP balance, and return what's left to
+/// distribute: `fee = pot * bps / PAYOUT_DENOM_BPS`, `distributable = pot - fee`
+fn skim_commission(env: &Env, pot: i128) -> Result<i128, Error> {
+    let bps = storage::get_game_commission_bps(env);
+    if bps == 0 {
+        return Ok(pot);
+    }
+
+    let fee = pot
+        .fixed_mul_floor(bps as i128, PAYOUT_DENOM_BPS)
+        .ok_or(Error::DivisionByZero)?;
+    let distributable = pot.checked_sub(fee).ok_or(Error::OverflowError)?;
+
+    if fee > 0 {
+        storage::add_treasury_fp(env, fee);
+    }
+
+    Ok(distributable)
+}
+
+// ============================================================================
+// N-Player Sessions
+// ============================================================================
+// `start_game`/`end_game` above are pinned to exactly two players and a
+// single winner, matching the `BlendizzardInterface` two-player games (like
+// number-guess) already integrate against - they're left untouched as a
+// convenience wrapper. `start_game_multi`/`end_game_multi` generalize the
+// same flow to any number of entrants sharing one pot, split by basis-point
+// payout shares rather than a single winner-take-all outcome.
+
+/// Start an N-player session, locking every entrant's wager into a single
+/// pot recorded as a `PotSession`
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `game_id` - Whitelisted game contract starting the session
+/// * `session_id` - The unique session identifier
+/// * `entrants` - Each entrant's address and wager, in pot order
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If `game_id` is not in the whitelist
+/// * `SessionAlreadyExists` - If `session_id` already has a session or pot
+/// * `InvalidAmount` - If `entrants` is empty or any wager is <= 0
+/// * `FactionNotSelected` - If any entrant hasn't selected a faction
+/// * `PlayerNotFound` - If any entrant's epoch data is missing after initialization
+/// * `InsufficientFactionPoints` - If any entrant can't afford their wager
+/// * `EpochNotOpen` - If the current epoch has been frozen/finalized
+pub(crate) fn start_game_multi(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    entrants: Vec<(Address, i128)>,
+) -> Result<(), Error> {
+    game_id.require_auth();
+
+    if !storage::is_game_whitelisted(env, game_id) {
+        return Err(Error::GameNotWhitelisted);
+    }
+
+    if storage::has_session(env, session_id) || storage::has_pot(env, session_id) {
+        return Err(Error::SessionAlreadyExists);
+    }
+
+    if entrants.is_empty() {
+        return Err(Error::InvalidAmount);
+    }
+    for (_, wager) in entrants.iter() {
+        if wager <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    storage::require_epoch_open(env, current_epoch)?;
+
+    for (entrant, wager) in entrants.iter() {
+        entrant.require_auth_for_args(vec![
+            &env,
+            game_id.to_val(),
+            session_id.into_val(env),
+            wager.into_val(env),
+        ]);
+
+        storage::get_player(env, &entrant).ok_or(Error::FactionNotSelected)?;
+
+        initialize_player_epoch(env, &entrant, current_epoch)?;
+        lock_epoch_faction(env, &entrant, current_epoch)?;
+        lock_fp(env, &entrant, wager, current_epoch)?;
+    }
+
+    storage::set_pot(
+        env,
+        session_id,
+        &PotSession {
+            game_id: game_id.clone(),
+            epoch_id: current_epoch,
+            entrants: entrants.clone(),
+            resolved: false,
+        },
+    );
+
+    // Records the start time the same way two-player sessions do.
+    // `resolve_expired_game` only looks up two-player sessions, so it
+    // returns `SessionNotFound` for an N-player pot.
+    storage::set_game_start_time(env, session_id, env.ledger().timestamp());
+
+    crate::events::emit_game_started_multi(env, game_id, session_id, &entrants);
+
+    Ok(())
+}
+
+/// End an N-player session, splitting the pot among `payouts` by basis-point
+/// share and crediting each recipient's faction contribution
+///
+/// `payouts` shares must sum to exactly `PAYOUT_DENOM_BPS` (10_000); a
+/// malformed payout table is rejected with `InvalidAmount` before any FP is
+/// credited.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `session_id` - The unique session identifier
+/// * `payouts` - Each winner's address and basis-point share of the pot
+///
+/// # Errors
+/// * `SessionNotFound` - If no pot exists for `session_id`
+/// * `InvalidSessionState` - If the pot was already resolved
+/// * `GameExpired` - If the pot is from a previous epoch
+/// * `EpochNotOpen` - If the current epoch has been frozen/finalized
+/// * `InvalidAmount` - If any individual share exceeds `PAYOUT_DENOM_BPS`, or
+///   the shares don't sum to exactly `PAYOUT_DENOM_BPS`
+/// * `OverflowError` - If the shares overflow while being summed
+/// * `PlayerNotFound` - If a payout recipient wasn't one of the original entrants
+pub(crate) fn end_game_multi(
+    env: &Env,
+    session_id: u32,
+    payouts: Vec<(Address, u32)>,
+) -> Result<(), Error> {
+    let mut pot = storage::get_pot(env, session_id).ok_or(Error::SessionNotFound)?;
+    pot.game_id.require_auth();
+
+    if pot.resolved {
+        return Err(Error::InvalidSessionState);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    if pot.epoch_id != current_epoch {
+        return Err(Error::GameExpired);
+    }
+    storage::require_epoch_open(env, current_epoch)?;
+
+    let mut total_bps: u32 = 0;
+    for (_, bps) in payouts.iter() {
+        if bps as i128 > PAYOUT_DENOM_BPS {
+            return Err(Error::InvalidAmount);
+        }
+        total_bps = total_bps.checked_add(bps).ok_or(Error::OverflowError)?;
+    }
+    if total_bps as i128 != PAYOUT_DENOM_BPS {
+        return Err(Error::InvalidAmount);
+    }
+
+    let mut total_pot: i128 = 0;
+    for (_, wager) in pot.entrants.iter() {
+        total_pot = total_pot.checked_add(wager).ok_or(Error::OverflowError)?;
+    }
+
+    // Skim the protocol commission off the whole pot once, then split what's
+    // left among the payout recipients - see `skim_commission`.
+    let distributable_pot = skim_commission(env, total_pot)?;
+
+    for (recipient, share_bps) in payouts.iter() {
+        if !pot.entrants.iter().any(|(addr, _)| addr == recipient) {
+            return Err(Error::PlayerNotFound);
+        }
+
+        let share = distributable_pot
+            .fixed_mul_floor(share_bps as i128, PAYOUT_DENOM_BPS)
+            .ok_or(Error::DivisionByZero)?;
+        if share == 0 {
+            continue;
+        }
+
+        let mut recipient_epoch = storage::get_epoch_player(env, current_epoch, &recipient)
+            .ok_or(Error::PlayerNotFound)?;
+
+        accrue_player_weighted_fp(env, current_epoch, &recipient)?;
+
+        recipient_epoch.total_fp_contributed = recipient_epoch
+            .total_fp_contributed
+            .checked_add(share)
+            .ok_or(Error::OverflowError)?;
+        storage::set_epoch_player(env, current_epoch, &recipient, &recipient_epoch);
+
+        update_faction_standings(env, &recipient, share, current_epoch)?;
+    }
+
+    pot.resolved = true;
+    storage::set_pot(env, session_id, &pot);
+
+    crate::events::emit_game_ended_multi(env, &pot.game_id, session_id, &payouts);
+
+    Ok(())
+}
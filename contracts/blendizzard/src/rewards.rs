@@ -1,11 +1,153 @@
 use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 
 use crate::errors::Error;
-use crate::events::emit_rewards_claimed;
+use crate::events::{emit_reward_settled, emit_rewards_claimed};
 use crate::storage;
 use crate::types::SCALAR_7;
 
+// ============================================================================
+// Reward Brackets
+// ============================================================================
+
+/// A rank-based slice of a winning faction's reward pool
+///
+/// Contributors whose FP rank falls within the top `top_percentile` (in
+/// `SCALAR_7` basis, e.g. `SCALAR_7 / 10` for the top 10%) split
+/// `pool_percent` (also `SCALAR_7` basis) of the distributable pool,
+/// proportionally to their FP within the bracket. Brackets in an epoch's
+/// bracket list must be ordered from the smallest `top_percentile` (the
+/// most exclusive bracket) to the largest, and the last bracket is treated
+/// as the catch-all that absorbs every remaining contributor and any
+/// rounding remainder left over from the tighter brackets above it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bracket {
+    /// Top percentile boundary for this bracket, in SCALAR_7 basis
+    pub top_percentile: i128,
+    /// Share of the distributable pool this bracket receives, in SCALAR_7 basis
+    pub pool_percent: i128,
+}
+
+/// Denominator for `TopContributorBonus::bonus_bps` and the main-pool share
+/// `settle_epoch_distribution` derives from it (`TRANCHE_DENOM_BPS - bonus_bps`)
+pub(crate) const TRANCHE_DENOM_BPS: i128 = 10_000;
+
+/// A flat bonus tranche for the top individual FP contributors to the winning
+/// faction, layered on top of the ordinary pro-rata split
+///
+/// `bonus_bps` (against `TRANCHE_DENOM_BPS`) of the distributable pool is
+/// carved out and split, pro-rata by FP, among only the `top_n` highest
+/// contributors (by `total_fp_contributed`). The remaining `TRANCHE_DENOM_BPS
+/// - bonus_bps` still splits pro-rata across every contributor, `top_n`
+/// included, so the bonus is additive rather than a reallocation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TopContributorBonus {
+    /// Number of highest-FP contributors who share the bonus tranche
+    pub top_n: u32,
+    /// Share of the distributable pool carved out for the bonus tranche, in
+    /// `TRANCHE_DENOM_BPS` basis
+    pub bonus_bps: i128,
+}
+
+/// A running, on-chain summary of an epoch's reward distribution, built up
+/// one claim at a time
+///
+/// Exists so indexers and front-ends have a durable source for "how much of
+/// this epoch's pool has actually been paid out" without replaying every
+/// `claim_epoch_reward` call or recomputing the division client-side.
+/// `total_winning_fp`/`reward_pool` are fixed the moment the first claim
+/// settles them (they don't change for the rest of the epoch);
+/// `cumulative_distributed`/`claimant_count` grow with every subsequent claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochRewardsSummary {
+    /// Winning faction's total FP used as the claim denominator (frozen at
+    /// finalization - see `storage::get_frozen_winning_fp`)
+    pub total_winning_fp: i128,
+    /// Post-commission pool this epoch's shares were computed against
+    pub reward_pool: i128,
+    /// Sum of every champion share paid out by `claim_epoch_reward` so far
+    /// (backer payouts split out of a champion's share are not counted
+    /// separately - they're already part of the champion share they came from)
+    pub cumulative_distributed: i128,
+    /// Number of distinct users who have claimed so far
+    pub claimant_count: u32,
+}
+
+/// Record one more settled claim against an epoch's running rewards summary,
+/// creating it on the first claim
+fn record_claim_in_summary(
+    env: &Env,
+    epoch: u32,
+    total_winning_fp: i128,
+    reward_pool: i128,
+    reward_amount: i128,
+) -> Result<(), Error> {
+    let mut summary = storage::get_epoch_rewards_summary(env, epoch).unwrap_or(EpochRewardsSummary {
+        total_winning_fp,
+        reward_pool,
+        cumulative_distributed: 0,
+        claimant_count: 0,
+    });
+
+    summary.cumulative_distributed = summary
+        .cumulative_distributed
+        .checked_add(reward_amount)
+        .ok_or(Error::OverflowError)?;
+    summary.claimant_count = summary.claimant_count.checked_add(1).ok_or(Error::OverflowError)?;
+
+    storage::set_epoch_rewards_summary(env, epoch, &summary);
+
+    Ok(())
+}
+
+/// Get an epoch's running rewards summary, if any claims have settled yet
+pub(crate) fn get_epoch_rewards_summary(env: &Env, epoch: u32) -> Option<EpochRewardsSummary> {
+    storage::get_epoch_rewards_summary(env, epoch)
+}
+
+/// If `user` and the winning faction both have time-weighted FP data
+/// recorded for this epoch, bring `user`'s accumulator forward to
+/// `end_time` and return `(weighted_user_fp, total_weighted_fp)` to use in
+/// place of the raw FP totals
+///
+/// Returns `None` when either side never accrued weighted data - e.g. the
+/// epoch predates time-weighted accrual, or a test harness wrote `EpochInfo`/
+/// `EpochPlayer` straight into storage without driving the real mutation
+/// paths. Callers fall back to the unweighted FP totals in that case.
+fn weighted_fp_override(
+    env: &Env,
+    epoch: u32,
+    user: &Address,
+    user_fp_contributed: i128,
+    end_time: u64,
+) -> Result<Option<(i128, i128)>, Error> {
+    let total_weighted_fp = match storage::get_frozen_winning_weighted_fp(env, epoch) {
+        Some(total) if total > 0 => total,
+        _ => return Ok(None),
+    };
+    let player_weighted = match storage::get_player_weighted_fp(env, epoch, user) {
+        Some(w) => w,
+        None => return Ok(None),
+    };
+
+    let elapsed = end_time
+        .checked_sub(player_weighted.last_update_t)
+        .ok_or(Error::OverflowError)?;
+    let weighted_user_fp = player_weighted
+        .weighted_fp
+        .checked_add(
+            user_fp_contributed
+                .checked_mul(elapsed as i128)
+                .ok_or(Error::OverflowError)?,
+        )
+        .ok_or(Error::OverflowError)?;
+
+    Ok(Some((weighted_user_fp, total_weighted_fp)))
+}
+
 // ============================================================================
 // Reward Distribution
 // ============================================================================
@@ -25,18 +167,397 @@ use crate::types::SCALAR_7;
 /// * `user` - User claiming rewards
 /// * `epoch` - Epoch number to claim from
 ///
+/// The claimed amount is deposited straight into the vault on `user`'s
+/// behalf rather than transferred to their wallet - see the deposit step
+/// below for why. If `user` is the winning faction's final claimant (every
+/// contributor the finalization snapshot recorded has now claimed), the
+/// floor-rounding dust every prior claim's truncation left behind is folded
+/// into this deposit too, see `final_claimant_dust`.
+///
 /// # Returns
-/// Amount of USDC claimed
+/// `(asset_amount, vault_shares)` - the USDC amount claimed (plus any
+/// final-claimant dust) and the vault shares it was deposited into
 ///
 /// # Errors
 /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
 /// * `RewardAlreadyClaimed` - If user already claimed for this epoch
 /// * `NotWinningFaction` - If user wasn't in the winning faction
 /// * `NoRewardsAvailable` - If user has no rewards to claim
-pub(crate) fn claim_epoch_reward(env: &Env, user: &Address, epoch: u32) -> Result<i128, Error> {
+/// * `RewardPoolExhausted` - If paying this claim would exceed the epoch's reward_pool
+/// * `VaultDepositFailed` - If the vault rejected depositing user's share
+pub(crate) fn claim_epoch_reward(env: &Env, user: &Address, epoch: u32) -> Result<(i128, i128), Error> {
     // Authenticate user
     user.require_auth();
 
+    let (champion_share, user_faction, backer_payouts) = settle_epoch_claim(env, user, epoch)?;
+
+    // If every contributor the winning faction's snapshot recorded has now
+    // claimed, fold the floor-rounding dust left behind by all of their
+    // truncated shares into this, the final claimant's, deposit - see
+    // `final_claimant_dust`.
+    let dust = final_claimant_dust(env, epoch, user_faction)?;
+    let deposit_amount = champion_share.checked_add(dust).ok_or(Error::OverflowError)?;
+
+    // Deposit the champion's share straight into the vault on `user`'s
+    // behalf rather than transferring USDC to their wallet first - that
+    // detour is exactly what used to leave funds stranded in the wallet if
+    // the vault then rejected a follow-up deposit. `set_claimed` is only
+    // recorded once the deposit actually succeeds, so a failed deposit
+    // simply never marks the epoch claimed and `user` can retry; there's
+    // nothing to unwind.
+    let vault_shares = crate::vault::deposit_for_player(env, user, deposit_amount)
+        .map_err(|_| Error::VaultDepositFailed)?;
+    storage::set_claimed(env, user, epoch);
+
+    // Emit event
+    emit_rewards_claimed(env, user, epoch, user_faction, deposit_amount);
+
+    // Anyone backing `user` via FP delegation gets their pro-rata cut of the
+    // same claim, paid out directly to their own wallet in the same
+    // transaction - only the champion's own share (plus any final-claimant
+    // dust) is vaulted.
+    let config = storage::get_config(env);
+    let usdc_client = soroban_sdk::token::Client::new(env, &config.usdc_token);
+    pay_backers(env, &usdc_client, epoch, user_faction, &backer_payouts);
+
+    Ok((deposit_amount, vault_shares))
+}
+
+/// Claim epoch reward for `beneficiary` on their behalf
+///
+/// Only `caller` needs to authenticate — the reward (and any vault shares it
+/// deposits into) still accrues entirely to `beneficiary`. This lets keeper
+/// bots and auto-compounders harvest stale rewards for idle players; the
+/// same `has_claimed` guard and proportional math as [`claim_epoch_reward`]
+/// apply, so `beneficiary` can't be double-charged or shorted by who
+/// triggers the claim.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `caller` - Address authorizing this call; need not be `beneficiary`
+/// * `beneficiary` - User whose rewards are being claimed
+/// * `epoch` - Epoch number to claim from
+///
+/// # Returns
+/// `(asset_amount, vault_shares)` claimed and deposited on `beneficiary`'s
+/// behalf
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardAlreadyClaimed` - If beneficiary already claimed for this epoch
+/// * `NotWinningFaction` - If beneficiary wasn't in the winning faction
+/// * `NoRewardsAvailable` - If beneficiary has no rewards to claim
+/// * `RewardPoolExhausted` - If paying this claim would exceed the epoch's reward_pool
+/// * `VaultDepositFailed` - If the vault rejected depositing beneficiary's share
+pub(crate) fn claim_epoch_reward_for(
+    env: &Env,
+    caller: &Address,
+    beneficiary: &Address,
+    epoch: u32,
+) -> Result<(i128, i128), Error> {
+    // Only the caller needs to authorize this call; the payout still goes
+    // to beneficiary regardless of who triggers it.
+    caller.require_auth();
+
+    let (champion_share, user_faction, backer_payouts) = settle_epoch_claim(env, beneficiary, epoch)?;
+
+    // Same final-claimant dust rule as `claim_epoch_reward`.
+    let dust = final_claimant_dust(env, epoch, user_faction)?;
+    let deposit_amount = champion_share.checked_add(dust).ok_or(Error::OverflowError)?;
+
+    // Same deposit-before-claim ordering as `claim_epoch_reward`: beneficiary
+    // is only marked claimed once the vault actually accepts their share.
+    let vault_shares = crate::vault::deposit_for_player(env, beneficiary, deposit_amount)
+        .map_err(|_| Error::VaultDepositFailed)?;
+    storage::set_claimed(env, beneficiary, epoch);
+
+    // Emit event
+    emit_rewards_claimed(env, beneficiary, epoch, user_faction, deposit_amount);
+
+    // Anyone backing `beneficiary` via FP delegation gets their pro-rata cut
+    // of the same claim, paid out directly regardless of who triggered it.
+    let config = storage::get_config(env);
+    let usdc_client = soroban_sdk::token::Client::new(env, &config.usdc_token);
+    pay_backers(env, &usdc_client, epoch, user_faction, &backer_payouts);
+
+    Ok((deposit_amount, vault_shares))
+}
+
+/// Claim rewards for several epochs in a single transaction
+///
+/// Authenticates once, then settles each epoch in `epochs` in turn. Epochs
+/// where the user has already claimed, that aren't finalized, or where the
+/// user wasn't in the winning faction are skipped rather than failing the
+/// whole batch. The user's own share across all settled epochs is paid out
+/// in a single aggregate USDC transfer; any backers delegating FP to the
+/// user are paid individually per epoch, since their pro-rata cut goes to a
+/// different address than the aggregate transfer.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - User claiming rewards
+/// * `epochs` - Epoch numbers to attempt to claim
+///
+/// # Returns
+/// The total amount claimed by `user` plus a `(epoch, amount)` breakdown for
+/// each epoch that was actually claimed
+pub(crate) fn claim_epochs(
+    env: &Env,
+    user: &Address,
+    epochs: Vec<u32>,
+) -> Result<(i128, Vec<(u32, i128)>), Error> {
+    // Authenticate user once for the whole batch
+    user.require_auth();
+
+    claim_many(env, user, epochs)
+}
+
+/// Claim rewards for every finalized epoch up to the current one the user
+/// hasn't already claimed
+///
+/// Convenience wrapper around [`claim_all`] that scans `0..=current_epoch`
+/// instead of requiring the caller to track which epochs they participated in.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - User claiming rewards
+///
+/// # Returns
+/// The total amount claimed plus a `(epoch, amount)` breakdown
+pub(crate) fn claim_all_unclaimed(env: &Env, user: &Address) -> Result<(i128, Vec<(u32, i128)>), Error> {
+    let current_epoch = storage::get_current_epoch(env);
+    claim_all(env, user, 0, current_epoch)
+}
+
+/// Claim rewards for every finalized epoch in `from_epoch..=to_epoch` the
+/// user hasn't already claimed, in a single transaction
+///
+/// Following Substrate's `claimed_rewards` range-tracking, this is the
+/// intended way to catch up after missing several epochs: every epoch in
+/// range that's eligible is settled and marked claimed in one
+/// `storage::set_claimed_many` write, rather than one storage write per
+/// epoch. Epochs that aren't finalized, that the user already claimed, or
+/// where the user wasn't in the winning faction are skipped rather than
+/// failing the whole range.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - User claiming rewards
+/// * `from_epoch` - First epoch in the range (inclusive)
+/// * `to_epoch` - Last epoch in the range (inclusive)
+///
+/// # Returns
+/// The total amount claimed plus a `(epoch, amount)` breakdown
+pub(crate) fn claim_all(
+    env: &Env,
+    user: &Address,
+    from_epoch: u32,
+    to_epoch: u32,
+) -> Result<(i128, Vec<(u32, i128)>), Error> {
+    user.require_auth();
+
+    let mut epochs: Vec<u32> = Vec::new(env);
+    let mut epoch = from_epoch;
+    while epoch <= to_epoch {
+        epochs.push_back(epoch);
+        epoch += 1;
+    }
+
+    claim_many(env, user, epochs)
+}
+
+/// Shared core of `claim_epochs`/`claim_all`: settle each epoch in `epochs`,
+/// skipping any that aren't eligible, pay out the aggregate total in one
+/// USDC transfer, and mark every settled epoch claimed with a single
+/// `storage::set_claimed_many` write. Callers are responsible for
+/// authenticating `user` first.
+fn claim_many(
+    env: &Env,
+    user: &Address,
+    epochs: Vec<u32>,
+) -> Result<(i128, Vec<(u32, i128)>), Error> {
+    let mut total_claimed: i128 = 0;
+    let mut breakdown: Vec<(u32, i128)> = Vec::new(env);
+    let mut claimed_epochs: Vec<u32> = Vec::new(env);
+    let config = storage::get_config(env);
+    let usdc_client = soroban_sdk::token::Client::new(env, &config.usdc_token);
+
+    for epoch in epochs.iter() {
+        match settle_epoch_claim(env, user, epoch) {
+            Ok((champion_share, user_faction, backer_payouts)) => {
+                // Same final-claimant dust rule as `claim_epoch_reward` - a
+                // batch claim still needs to advance `EpochClaimantCount`, or
+                // an epoch where even one winning-faction member claims
+                // through this path can never detect its final claimant.
+                let dust = final_claimant_dust(env, epoch, user_faction)?;
+                let claimed_amount = champion_share.checked_add(dust).ok_or(Error::OverflowError)?;
+
+                total_claimed = total_claimed
+                    .checked_add(claimed_amount)
+                    .ok_or(Error::OverflowError)?;
+                breakdown.push_back((epoch, claimed_amount));
+                claimed_epochs.push_back(epoch);
+                emit_rewards_claimed(env, user, epoch, user_faction, claimed_amount);
+                pay_backers(env, &usdc_client, epoch, user_faction, &backer_payouts);
+            }
+            Err(_) => continue,
+        }
+    }
+
+    if !claimed_epochs.is_empty() {
+        storage::set_claimed_many(env, user, &claimed_epochs);
+    }
+
+    if total_claimed > 0 {
+        usdc_client.transfer(&env.current_contract_address(), user, &total_claimed);
+    }
+
+    Ok((total_claimed, breakdown))
+}
+
+/// Default number of consecutive non-claimable epochs `claim_epochs_from`
+/// tolerates before giving up a forward scan
+const CLAIM_SCAN_GAP_LIMIT: u32 = 20;
+
+/// Claim rewards forward from `from_epoch`, stopping once `max_count` epochs
+/// have been claimed, without requiring the caller to know which epochs it
+/// participated in
+///
+/// `claim_epochs`/`claim_all` already cover claiming an explicit or bounded
+/// range of epochs in one transaction, but both require the caller to know
+/// (or compute) an upper bound, and both walk every epoch in that range even
+/// if the player never touched most of them. This instead walks forward one
+/// epoch at a time, stopping after `max_count` successful claims or after
+/// [`CLAIM_SCAN_GAP_LIMIT`] consecutive epochs in a row turn out to be
+/// unclaimable (not finalized, already claimed, or not in the winning
+/// faction) - so a player who only played a handful of epochs years apart
+/// doesn't force the scan to walk every epoch in between, and a scan
+/// starting past the current epoch can't run unbounded.
+///
+/// Unlike `claim_epochs`/`claim_all`, each epoch here is claimed through
+/// [`claim_epoch_reward`] directly, so the champion's share is deposited
+/// into the vault per epoch exactly as a single claim would be, rather than
+/// batched into one aggregate wallet transfer.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `user` - User claiming rewards
+/// * `from_epoch` - First epoch to scan (inclusive)
+/// * `max_count` - Maximum number of epochs to claim in this call
+///
+/// # Returns
+/// `(epoch -> claimed asset amount, total vault shares minted across every
+/// epoch claimed)`
+pub(crate) fn claim_epochs_from(
+    env: &Env,
+    user: &Address,
+    from_epoch: u32,
+    max_count: u32,
+) -> Result<(Map<u32, i128>, i128), Error> {
+    // Authenticate once; `claim_epoch_reward` re-checking auth per epoch is
+    // harmless, it's the same invocation's authorization being consulted.
+    user.require_auth();
+
+    let mut claimed: Map<u32, i128> = Map::new(env);
+    let mut total_shares: i128 = 0;
+    let mut epoch = from_epoch;
+    let mut consecutive_misses: u32 = 0;
+
+    while claimed.len() < max_count && consecutive_misses < CLAIM_SCAN_GAP_LIMIT {
+        match claim_epoch_reward(env, user, epoch) {
+            Ok((amount, shares)) => {
+                claimed.set(epoch, amount);
+                total_shares = total_shares.checked_add(shares).ok_or(Error::OverflowError)?;
+                consecutive_misses = 0;
+            }
+            Err(_) => {
+                consecutive_misses += 1;
+            }
+        }
+
+        epoch = match epoch.checked_add(1) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+
+    Ok((claimed, total_shares))
+}
+
+/// If `claimant`'s claim was the winning faction's last outstanding one,
+/// hand them the floor-rounding dust every prior claim's truncation left
+/// behind instead of leaving it stranded in the contract
+///
+/// Every `calculate_reward_share` floors, so `distributable_pool -
+/// claimed_total` is never quite zero once every contributor has claimed -
+/// the same gap `sweep_epoch_dust` lets the treasury collect later. Folding
+/// it into the final claimant's own deposit instead means most epochs never
+/// need that separate admin call at all. Relies on `record_faction_snapshot`
+/// having recorded the winning faction's contributor list for this epoch;
+/// epochs with no snapshot recorded fall back to requiring the explicit
+/// `sweep_epoch_dust` call, exactly as before this existed. Idempotent with
+/// `sweep_epoch_dust` via the shared `DustSwept` flag - whichever happens
+/// first claims the dust, not both.
+///
+/// # Returns
+/// The dust amount to add to `claimant`'s own deposit - `0` if this wasn't
+/// the final claimant, the snapshot is missing, or there's no dust left
+fn final_claimant_dust(env: &Env, epoch: u32, winning_faction: u32) -> Result<i128, Error> {
+    let claimant_count = storage::increment_claimant_count(env, epoch);
+
+    let snapshot = match storage::get_faction_snapshot(env, epoch, winning_faction) {
+        Some(snapshot) => snapshot,
+        None => return Ok(0),
+    };
+
+    if claimant_count < snapshot.len() {
+        return Ok(0);
+    }
+
+    if storage::has_dust_swept(env, epoch) {
+        return Ok(0);
+    }
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    let config = storage::get_config(env);
+    let distributable_pool = net_of_commission(epoch_info.reward_pool, config.commission_rate)?;
+    let claimed_total = storage::get_claimed_total(env, epoch);
+
+    let dust = distributable_pool
+        .checked_sub(claimed_total)
+        .ok_or(Error::OverflowError)?;
+    if dust <= 0 {
+        return Ok(0);
+    }
+
+    storage::set_dust_swept(env, epoch);
+    storage::set_recorded_dust(env, epoch, dust);
+
+    Ok(dust)
+}
+
+/// Validate and settle a single epoch's claim without moving funds
+///
+/// Performs all the eligibility checks, enforces the per-epoch payout
+/// invariant, and adds the payout to the epoch's `claimed_total`. It does
+/// not mark the epoch claimed for `user`. The caller is responsible for
+/// that, for authentication, and for transferring `reward_amount` (here
+/// split into `user`'s own share and any backers' pro-rata shares).
+///
+/// # Returns
+/// `(champion_share, user_faction, backer_payouts)`
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardAlreadyClaimed` - If user already claimed for this epoch
+/// * `NotWinningFaction` - If user wasn't in the winning faction
+/// * `NoRewardsAvailable` - If user has no rewards to claim
+/// * `RewardPoolExhausted` - If paying this claim would exceed the epoch's reward_pool
+fn settle_epoch_claim(
+    env: &Env,
+    user: &Address,
+    epoch: u32,
+) -> Result<(i128, u32, Vec<(Address, i128)>), Error> {
     // Check if already claimed
     if storage::has_claimed(env, user, epoch) {
         return Err(Error::RewardAlreadyClaimed);
@@ -50,6 +571,17 @@ pub(crate) fn claim_epoch_reward(env: &Env, user: &Address, epoch: u32) -> Resul
         return Err(Error::EpochNotFinalized);
     }
 
+    // Finalization only determines the winning faction - `cycle_epoch`'s
+    // later Swapping phase is what actually funds `reward_pool` from the
+    // BLND/USDC swap. A claim that lands in that gap would otherwise just
+    // compute a share of a zero pool and fail with the same
+    // `NoRewardsAvailable` a genuinely FP-less player gets, which reads as
+    // "you have nothing coming" rather than "come back once the pool is
+    // funded". Surface that gap as its own error instead.
+    if epoch_info.reward_pool == 0 {
+        return Err(Error::EpochNotSettled);
+    }
+
     // Get winning faction
     let winning_faction = epoch_info.winning_faction.ok_or(Error::EpochNotFinalized)?;
 
@@ -70,40 +602,184 @@ pub(crate) fn claim_epoch_reward(env: &Env, user: &Address, epoch: u32) -> Resul
         return Err(Error::NoRewardsAvailable);
     }
 
-    // Get total fp for winning faction
-    let total_winning_fp = epoch_info
-        .faction_standings
-        .get(winning_faction)
-        .ok_or(Error::NoRewardsAvailable)?;
+    // Get total fp for winning faction. Prefer the total `finalize_epoch`
+    // froze at finalization time over the live, still-mutable standing -
+    // this is what stops a contribution that lands in this epoch after
+    // finalization from inflating the denominator and diluting every
+    // earlier claimant's share. Epochs finalized before this freeze existed
+    // have nothing frozen, so they fall back to the live total unchanged.
+    let total_winning_fp = match storage::get_frozen_winning_fp(env, epoch) {
+        Some(frozen) => frozen,
+        None => epoch_info
+            .faction_standings
+            .get(winning_faction)
+            .ok_or(Error::NoRewardsAvailable)?,
+    };
 
     if total_winning_fp == 0 {
         return Err(Error::DivisionByZero);
     }
 
-    // Calculate user's share of rewards
-    // Formula: (user_fp / total_fp) * reward_pool
-    let reward_amount = calculate_reward_share(
-        user_fp_contributed,
-        total_winning_fp,
-        epoch_info.reward_pool,
-    )?;
+    // Prefer time-weighted FP over raw FP for the plain pro-rata split, so a
+    // contribution dumped in the epoch's final seconds doesn't capture the
+    // same share as one held all epoch. Falls back to `None` for epochs that
+    // never accrued weighted data.
+    let weighted_override =
+        weighted_fp_override(env, epoch, user, user_fp_contributed, epoch_info.end_time)?;
+
+    // Commission is skimmed off the top before the proportional FP math runs,
+    // so players split reward_pool * (1 - commission_rate), not the raw pool.
+    let config = storage::get_config(env);
+    let distributable_pool = net_of_commission(epoch_info.reward_pool, config.commission_rate)?;
+
+    // Calculate user's share of rewards. If the epoch has tiered brackets
+    // configured, standout contributors are rewarded from rank-based slices
+    // of the pool instead of a single faction-wide linear split.
+    // If `settle_epoch_distribution` has already run for this epoch, pay
+    // the exact, dust-included amount it computed instead of recomputing
+    // the (dust-unaware) share on the fly.
+    let reward_amount = if storage::has_dust_settled(env, epoch) {
+        storage::get_settled_reward(env, epoch, user).ok_or(Error::NoRewardsAvailable)?
+    } else {
+        match storage::get_epoch_brackets(env, epoch) {
+            Some(brackets) if !brackets.is_empty() => calculate_bracket_reward_share(
+                env,
+                epoch,
+                winning_faction,
+                user,
+                user_fp_contributed,
+                &brackets,
+                distributable_pool,
+            )?,
+            _ => match weighted_override {
+                Some((weighted_user, weighted_total)) => {
+                    calculate_reward_share(weighted_user, weighted_total, distributable_pool)?
+                }
+                None => calculate_reward_share(user_fp_contributed, total_winning_fp, distributable_pool)?,
+            },
+        }
+    };
 
     if reward_amount == 0 {
         return Err(Error::NoRewardsAvailable);
     }
 
-    // Mark as claimed
-    storage::set_claimed(env, user, epoch);
+    // Enforce the per-epoch payout invariant: the contract must never pay out
+    // more than the distributable (post-commission) pool, even if rounding
+    // were to favor claimants across many small payouts.
+    let claimed_total = storage::get_claimed_total(env, epoch);
+    let new_claimed_total = claimed_total
+        .checked_add(reward_amount)
+        .ok_or(Error::OverflowError)?;
+    if new_claimed_total > distributable_pool {
+        return Err(Error::RewardPoolExhausted);
+    }
 
-    // Transfer USDC to user
-    let config = storage::get_config(env);
-    let usdc_client = soroban_sdk::token::Client::new(env, &config.usdc_token);
-    usdc_client.transfer(&env.current_contract_address(), user, &reward_amount);
+    // NOTE: marking the epoch claimed is the caller's responsibility - single
+    // claims call `storage::set_claimed` directly, while batched claims
+    // (`claim_epochs`/`claim_all`) batch every epoch in the range into one
+    // `storage::set_claimed_many` write instead of one per epoch.
+    storage::add_claimed_total(env, epoch, reward_amount);
 
-    // Emit event
-    emit_rewards_claimed(env, user, epoch, user_faction, reward_amount);
+    // The plain pro-rata floor against the frozen denominator, regardless of
+    // which branch above actually computed `reward_amount` - this is the
+    // baseline an indexer can reconstruct independently; `remainder_awarded`
+    // is whatever time-weighting, brackets, the bonus tranche, or
+    // largest-remainder dust settlement added on top of (or took off) it.
+    let floor_share = calculate_reward_share(user_fp_contributed, total_winning_fp, distributable_pool)?;
+    let remainder_awarded = reward_amount
+        .checked_sub(floor_share)
+        .ok_or(Error::OverflowError)?;
+    emit_reward_settled(
+        env,
+        user,
+        epoch,
+        user_fp_contributed,
+        total_winning_fp,
+        floor_share,
+        remainder_awarded,
+    );
+    record_claim_in_summary(env, epoch, total_winning_fp, distributable_pool, reward_amount)?;
+
+    // `user_fp_contributed` already folds in any FP backing `user` (see
+    // `crate::game::apply_game_outcome`), so `reward_amount` was
+    // computed over the combined pool - split it back apart here.
+    let (champion_share, backer_payouts) =
+        split_reward_with_backers(env, epoch, user, user_fp_contributed, reward_amount)?;
+
+    Ok((champion_share, user_faction, backer_payouts))
+}
+
+/// Split a champion's reward between themself and anyone backing them via
+/// FP delegation
+///
+/// `reward_amount` was computed over `champion_fp_contributed`, which
+/// already includes backing FP. This splits it back apart: backers take the
+/// slice proportional to their backing out of the champion's total
+/// contribution, divided among themselves pro-rata by
+/// `backer_amount / total_backing` (Substrate staking's nominator/validator
+/// payout split); the champion keeps the remainder, so rounding dust favors
+/// the champion rather than going unaccounted for.
+///
+/// # Returns
+/// `(champion_share, backer_payouts)` - `backer_payouts` is empty if the
+/// champion has no backers this epoch
+fn split_reward_with_backers(
+    env: &Env,
+    epoch: u32,
+    champion: &Address,
+    champion_fp_contributed: i128,
+    reward_amount: i128,
+) -> Result<(i128, Vec<(Address, i128)>), Error> {
+    let backers = crate::game::get_backers(env, epoch, champion);
+    if backers.is_empty() {
+        return Ok((reward_amount, Vec::new(env)));
+    }
+
+    let total_backing = crate::game::total_backing_for(env, epoch, champion);
+    if total_backing == 0 {
+        return Ok((reward_amount, Vec::new(env)));
+    }
+
+    let backing_pool = reward_amount
+        .fixed_mul_floor(total_backing, champion_fp_contributed)
+        .ok_or(Error::DivisionByZero)?;
+
+    let mut payouts: Vec<(Address, i128)> = Vec::new(env);
+    let mut paid_to_backers: i128 = 0;
+    for backer in backers.iter() {
+        let delegation = storage::get_delegation(env, epoch, &backer).ok_or(Error::NoRewardsAvailable)?;
+        let share = backing_pool
+            .fixed_mul_floor(delegation.amount, total_backing)
+            .ok_or(Error::DivisionByZero)?;
+        if share > 0 {
+            payouts.push_back((backer.clone(), share));
+            paid_to_backers = paid_to_backers
+                .checked_add(share)
+                .ok_or(Error::OverflowError)?;
+        }
+    }
+
+    let champion_share = reward_amount
+        .checked_sub(paid_to_backers)
+        .ok_or(Error::OverflowError)?;
 
-    Ok(reward_amount)
+    Ok((champion_share, payouts))
+}
+
+/// Transfer each backer's pro-rata share and emit a claim event for it,
+/// reusing the same `RewardsClaimed` event the champion's own claim emits
+fn pay_backers(
+    env: &Env,
+    usdc_client: &soroban_sdk::token::Client,
+    epoch: u32,
+    faction: u32,
+    backer_payouts: &Vec<(Address, i128)>,
+) {
+    for (backer, amount) in backer_payouts.iter() {
+        usdc_client.transfer(&env.current_contract_address(), &backer, &amount);
+        emit_rewards_claimed(env, &backer, epoch, faction, amount);
+    }
 }
 
 // ============================================================================
@@ -112,8 +788,13 @@ pub(crate) fn claim_epoch_reward(env: &Env, user: &Address, epoch: u32) -> Resul
 
 /// Calculate user's share of the reward pool
 ///
-/// Formula: (user_fp_contributed / total_winning_fp) * reward_pool
-/// Uses fixed-point math to avoid overflow
+/// Formula: reward_pool * user_fp_contributed / total_winning_fp
+///
+/// Computed in a single rounding step (multiply before divide, via a widened
+/// i128 intermediate) rather than dividing to get a `share` and then
+/// multiplying that floored share back into the pool. Two floors compound
+/// truncation error and can under-pay small contributors; one floor here
+/// matches the precision of a direct integer mul-div.
 ///
 /// # Arguments
 /// * `user_fp` - User's total fp contributed
@@ -124,20 +805,532 @@ pub(crate) fn claim_epoch_reward(env: &Env, user: &Address, epoch: u32) -> Resul
 /// User's reward amount in USDC
 ///
 /// # Errors
-/// * `OverflowError` - If calculation overflows
 /// * `DivisionByZero` - If total_fp is 0
 fn calculate_reward_share(user_fp: i128, total_fp: i128, reward_pool: i128) -> Result<i128, Error> {
-    // Calculate user's share as a fraction: user_fp / total_fp
-    let share = user_fp
-        .fixed_div_floor(total_fp, SCALAR_7)
-        .ok_or(Error::DivisionByZero)?;
+    reward_pool
+        .fixed_mul_floor(user_fp, total_fp)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// Withhold the protocol/game commission from a reward pool
+///
+/// `commission_rate` is expressed in `SCALAR_7` basis (e.g. `SCALAR_7 / 10`
+/// for 10%). Returns `reward_pool * (1 - commission_rate)`.
+///
+/// # Errors
+/// * `OverflowError` - If the calculation overflows
+fn net_of_commission(reward_pool: i128, commission_rate: i128) -> Result<i128, Error> {
+    if commission_rate == 0 {
+        return Ok(reward_pool);
+    }
+
+    let commission = reward_pool
+        .fixed_mul_floor(commission_rate, SCALAR_7)
+        .ok_or(Error::OverflowError)?;
+
+    reward_pool
+        .checked_sub(commission)
+        .ok_or(Error::OverflowError)
+}
+
+/// Compute the commission amount withheld from an epoch's reward pool
+fn commission_amount(reward_pool: i128, commission_rate: i128) -> Result<i128, Error> {
+    let distributable = net_of_commission(reward_pool, commission_rate)?;
+    reward_pool
+        .checked_sub(distributable)
+        .ok_or(Error::OverflowError)
+}
+
+// ============================================================================
+// Commission
+// ============================================================================
+
+/// Withdraw the commission withheld from an epoch's reward pool
+///
+/// Only the treasury/game-operator address configured in `Config` may call
+/// this, and each epoch's commission can only be withdrawn once, mirroring
+/// the `has_claimed` guard used for player reward claims.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Epoch number to withdraw commission from
+///
+/// # Returns
+/// Amount of USDC withdrawn as commission
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardAlreadyClaimed` - If the commission was already withdrawn for this epoch
+/// * `NoRewardsAvailable` - If there is no commission to withdraw
+pub(crate) fn claim_commission(env: &Env, epoch: u32) -> Result<i128, Error> {
+    let config = storage::get_config(env);
+    config.treasury.require_auth();
+
+    if storage::has_commission_claimed(env, epoch) {
+        return Err(Error::RewardAlreadyClaimed);
+    }
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.is_finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let amount = commission_amount(epoch_info.reward_pool, config.commission_rate)?;
+    if amount == 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    storage::set_commission_claimed(env, epoch);
+
+    let usdc_client = soroban_sdk::token::Client::new(env, &config.usdc_token);
+    usdc_client.transfer(&env.current_contract_address(), &config.treasury, &amount);
+
+    Ok(amount)
+}
+
+// ============================================================================
+// Dust Reconciliation
+// ============================================================================
+
+/// Sweep the floored remainder left in an epoch's distributable pool after
+/// claims
+///
+/// Every claim rounds down, so once an epoch's winning-faction participants
+/// have claimed (or it's simply been a while since finalization), some dust
+/// is permanently unclaimable through normal `claim_epoch_reward` calls.
+/// This deposits that remainder into the vault on the treasury's behalf
+/// instead of leaving it stranded in the contract forever - the same
+/// deposit-based settlement every regular claim now uses. Can only be swept
+/// once per epoch; claimants who show up after the sweep still draw from
+/// the original distributable pool, since `claimed_total` (not the swept
+/// amount) is what sweeping consumes. A no-op if `final_claimant_dust`
+/// already swept this epoch's dust into the last claimant's own deposit -
+/// both share the same `DustSwept` flag.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Epoch number to sweep dust from
+///
+/// # Returns
+/// Amount of USDC dust deposited into the vault
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardAlreadyClaimed` - If dust was already swept for this epoch
+/// * `NoRewardsAvailable` - If there is no dust to sweep
+/// * `VaultDepositFailed` - If the vault rejected depositing the dust
+pub(crate) fn sweep_epoch_dust(env: &Env, epoch: u32) -> Result<i128, Error> {
+    let config = storage::get_config(env);
+    config.treasury.require_auth();
+
+    if storage::has_dust_swept(env, epoch) {
+        return Err(Error::RewardAlreadyClaimed);
+    }
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.is_finalized {
+        return Err(Error::EpochNotFinalized);
+    }
 
-    // Calculate reward: share * reward_pool
-    let reward = reward_pool
-        .fixed_mul_floor(share, SCALAR_7)
+    let distributable_pool = net_of_commission(epoch_info.reward_pool, config.commission_rate)?;
+    let claimed_total = storage::get_claimed_total(env, epoch);
+
+    let dust = distributable_pool
+        .checked_sub(claimed_total)
         .ok_or(Error::OverflowError)?;
+    if dust <= 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
 
-    Ok(reward)
+    storage::set_dust_swept(env, epoch);
+    storage::set_recorded_dust(env, epoch, dust);
+
+    crate::vault::deposit_for_player(env, &config.treasury, dust).map_err(|_| Error::VaultDepositFailed)?;
+
+    Ok(dust)
+}
+
+// ============================================================================
+// Deterministic Dust-Free Settlement
+// ============================================================================
+// `claim_epoch_reward` computes each claimant's share lazily, one player at
+// a time, so the pool's floor-rounding dust only ever shows up as whatever's
+// left unclaimed (see `sweep_epoch_dust` above) - that leftover can't tell a
+// genuine non-claimant apart from pure rounding loss. `settle_epoch_distribution`
+// instead walks a faction's full, admin-recorded snapshot
+// (`record_faction_snapshot`) once, computing every contributor's exact
+// floor-rounded share in a single deterministic pass via `settle_largest_remainder`,
+// then closes out the pool exactly via the largest-remainder rule: rank
+// contributors by how much their floor-rounded share shortchanged them -
+// `(contribution * pool) mod total_contributed` - and hand the leftover
+// lamports one at a time to the largest remainders, ties broken by address
+// order. This spreads unavoidable rounding loss across the contributors it
+// actually came from, rather than concentrating it on one address. Once
+// settled, `claim_epoch_reward`/`get_claimable_amount` pay out the settled
+// amount directly instead of recomputing it.
+
+/// Split `pool` across `weighted` (address, FP-weight pairs) via the
+/// largest-remainder rule, returning each address's floor-rounded share plus
+/// at most one extra unit of dust
+///
+/// Every share is `pool * weight_i / total_weight`, floored, computed with a
+/// single multiply-before-divide so there's only one rounding step per
+/// contributor. Whatever's left behind by flooring (`< weighted.len()` units)
+/// goes one at a time to the largest remainders, ties broken by address order
+/// so the result is fully deterministic. Does not touch storage - callers
+/// combine and persist shares themselves, since `settle_epoch_distribution`
+/// needs to sum a contributor's main-pool and bonus-pool shares before
+/// writing a single settled amount.
+///
+/// # Errors
+/// * `DivisionByZero` - If `weighted`'s weights sum to zero
+fn settle_largest_remainder(
+    env: &Env,
+    pool: i128,
+    weighted: &Vec<(Address, i128)>,
+) -> Result<Vec<(Address, i128)>, Error> {
+    let mut total_weight: i128 = 0;
+    for (_, weight) in weighted.iter() {
+        total_weight = total_weight.checked_add(weight).ok_or(Error::OverflowError)?;
+    }
+    if total_weight == 0 {
+        return Err(Error::DivisionByZero);
+    }
+
+    // First pass: every entry's floor-rounded share, and the remainder it
+    // left behind - `(weight * pool) mod total_weight`.
+    let mut distributed: i128 = 0;
+    let mut ranked: Vec<(Address, i128, i128)> = Vec::new(env); // (addr, remainder, share)
+    for (addr, weight) in weighted.iter() {
+        let product = pool.checked_mul(weight).ok_or(Error::OverflowError)?;
+        let share = product.checked_div(total_weight).ok_or(Error::DivisionByZero)?;
+        let remainder = product - share.checked_mul(total_weight).ok_or(Error::OverflowError)?;
+
+        distributed = distributed.checked_add(share).ok_or(Error::OverflowError)?;
+        ranked.push_back((addr, remainder, share));
+    }
+
+    // Invariant: flooring every share can only ever leave dust behind, never
+    // pay out more than the pool. A violation here means the math above has
+    // a bug, so - unlike everything else in this module - this panics
+    // rather than returning a recoverable error.
+    if distributed > pool {
+        panic!("tranche settlement overspent its pool");
+    }
+    let dust = pool - distributed;
+
+    // Second pass: largest-remainder rule. Exactly `dust` entries get one
+    // extra unit each - never more than `ranked.len()`, since every
+    // remainder is strictly less than `total_weight` and their sum is
+    // `dust * total_weight`.
+    let mut awarded: i128 = 0;
+    while awarded < dust {
+        let mut best_idx: u32 = 0;
+        let mut best_remainder: i128 = -1;
+        let mut best_addr: Option<Address> = None;
+        for i in 0..ranked.len() {
+            let (addr, remainder, _) = ranked.get(i).unwrap();
+            let better = remainder > best_remainder
+                || (remainder == best_remainder
+                    && match &best_addr {
+                        Some(current_best) => &addr < current_best,
+                        None => true,
+                    });
+            if better {
+                best_idx = i;
+                best_remainder = remainder;
+                best_addr = Some(addr);
+            }
+        }
+
+        let (addr, _, share) = ranked.get(best_idx).unwrap();
+        let new_share = share.checked_add(1).ok_or(Error::OverflowError)?;
+        // Exclude this contributor from future rounds by setting their remainder to -1.
+        ranked.set(best_idx, (addr.clone(), -1, new_share));
+        awarded = awarded.checked_add(1).ok_or(Error::OverflowError)?;
+    }
+
+    let mut shares = Vec::new(env);
+    for i in 0..ranked.len() {
+        let (addr, _, share) = ranked.get(i).unwrap();
+        shares.push_back((addr, share));
+    }
+    Ok(shares)
+}
+
+/// Settle an epoch's winning-faction distribution in one deterministic
+/// pass, closing out `reward_pool` exactly via the largest-remainder rule
+///
+/// Requires `record_faction_snapshot` to have recorded the winning
+/// faction's contributor list for this epoch. Can only run once per epoch -
+/// re-running it would assign the same dust twice.
+///
+/// If a `TopContributorBonus` is configured for the epoch, `bonus_bps` of
+/// the distributable pool is carved out and split, pro-rata by FP, among
+/// just the top `top_n` contributors (the snapshot is already sorted FP
+/// descending), on top of their share of the remaining pool - which still
+/// splits pro-rata across every contributor exactly as before. With no
+/// bonus configured, the whole pool splits pro-rata in a single tranche,
+/// unchanged from before this existed.
+///
+/// # Arguments
+/// * `env` - Contract environment
+/// * `epoch` - Epoch number to settle
+///
+/// # Returns
+/// Total amount distributed - always exactly the distributable pool
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardAlreadyClaimed` - If the epoch was already settled
+/// * `NoRewardsAvailable` - If no faction snapshot was recorded for the winning faction
+/// * `DivisionByZero` - If the snapshot's contributors sum to zero FP
+pub(crate) fn settle_epoch_distribution(env: &Env, epoch: u32) -> Result<i128, Error> {
+    if storage::has_dust_settled(env, epoch) {
+        return Err(Error::RewardAlreadyClaimed);
+    }
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.is_finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+    let winning_faction = epoch_info.winning_faction.ok_or(Error::EpochNotFinalized)?;
+
+    let snapshot = storage::get_faction_snapshot(env, epoch, winning_faction)
+        .ok_or(Error::NoRewardsAvailable)?;
+    if snapshot.is_empty() {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    let config = storage::get_config(env);
+    let distributable_pool = net_of_commission(epoch_info.reward_pool, config.commission_rate)?;
+
+    let bonus = storage::get_top_contributor_bonus(env, epoch);
+    let bonus_pool = match &bonus {
+        Some(b) if b.bonus_bps > 0 => distributable_pool
+            .fixed_mul_floor(b.bonus_bps, TRANCHE_DENOM_BPS)
+            .ok_or(Error::OverflowError)?,
+        _ => 0,
+    };
+    let main_pool = distributable_pool - bonus_pool;
+
+    let mut shares: Vec<(Address, i128)> = settle_largest_remainder(env, main_pool, &snapshot)?;
+
+    if bonus_pool > 0 {
+        let top_n = bonus.unwrap().top_n;
+        let mut top_contributors: Vec<(Address, i128)> = Vec::new(env);
+        for i in 0..snapshot.len().min(top_n) {
+            top_contributors.push_back(snapshot.get(i).unwrap());
+        }
+        if !top_contributors.is_empty() {
+            let bonus_shares = settle_largest_remainder(env, bonus_pool, &top_contributors)?;
+            for (addr, bonus_share) in bonus_shares.iter() {
+                for i in 0..shares.len() {
+                    let (existing_addr, existing_share) = shares.get(i).unwrap();
+                    if existing_addr == addr {
+                        shares.set(
+                            i,
+                            (
+                                existing_addr,
+                                existing_share.checked_add(bonus_share).ok_or(Error::OverflowError)?,
+                            ),
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut distributed: i128 = 0;
+    for (addr, share) in shares.iter() {
+        storage::set_settled_reward(env, epoch, &addr, share);
+        distributed = distributed.checked_add(share).ok_or(Error::OverflowError)?;
+    }
+
+    storage::set_dust_settled(env, epoch);
+
+    Ok(distributed)
+}
+
+/// Calculate a user's reward under tiered bracket distribution
+///
+/// Determines which bracket `user`'s FP rank falls into from the faction's
+/// finalization snapshot, then splits that bracket's slice of
+/// `distributable_pool` proportionally to FP among bracket members. The
+/// last bracket in `brackets` is the catch-all: its effective `pool_percent`
+/// is whatever remains after the tighter brackets above it, so the full set
+/// of brackets always exactly covers `distributable_pool` regardless of how
+/// the configured percents round.
+///
+/// # Errors
+/// * `NoRewardsAvailable` - If no finalization snapshot was recorded for the faction, or user isn't in it
+/// * `DivisionByZero` - If a bracket has no FP contributed by its members
+fn calculate_bracket_reward_share(
+    env: &Env,
+    epoch: u32,
+    winning_faction: u32,
+    user: &Address,
+    user_fp: i128,
+    brackets: &Vec<Bracket>,
+    distributable_pool: i128,
+) -> Result<i128, Error> {
+    let snapshot = storage::get_faction_snapshot(env, epoch, winning_faction)
+        .ok_or(Error::NoRewardsAvailable)?;
+
+    let total_participants = snapshot.len() as i128;
+    if total_participants == 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    let mut user_rank: Option<u32> = None;
+    for (i, (addr, _fp)) in snapshot.iter().enumerate() {
+        if &addr == user {
+            user_rank = Some(i as u32);
+            break;
+        }
+    }
+    let user_rank = user_rank.ok_or(Error::NoRewardsAvailable)?;
+
+    let last_index = brackets.len() - 1;
+    let user_percentile = percentile_of_rank(user_rank as i128, total_participants)?;
+    let bracket_index = bracket_for_percentile(brackets, last_index, user_percentile);
+    let bracket = brackets.get(bracket_index).ok_or(Error::NoRewardsAvailable)?;
+
+    // The catch-all (last) bracket absorbs whatever percent the earlier
+    // brackets didn't use, so the pool is always fully covered regardless of
+    // how the configured percents round.
+    let effective_pool_percent = if bracket_index == last_index {
+        let mut prior_pool_percent: i128 = 0;
+        for i in 0..last_index {
+            let earlier = brackets.get(i).ok_or(Error::NoRewardsAvailable)?;
+            prior_pool_percent = prior_pool_percent
+                .checked_add(earlier.pool_percent)
+                .ok_or(Error::OverflowError)?;
+        }
+        SCALAR_7
+            .checked_sub(prior_pool_percent)
+            .ok_or(Error::OverflowError)?
+    } else {
+        bracket.pool_percent
+    };
+
+    let bracket_pool = distributable_pool
+        .fixed_mul_floor(effective_pool_percent, SCALAR_7)
+        .ok_or(Error::OverflowError)?;
+
+    // Sum FP contributed by every member who falls in the same bracket
+    let mut bracket_total_fp: i128 = 0;
+    for (i, (_addr, fp)) in snapshot.iter().enumerate() {
+        let percentile = percentile_of_rank(i as i128, total_participants)?;
+        if bracket_for_percentile(brackets, last_index, percentile) == bracket_index {
+            bracket_total_fp = bracket_total_fp.checked_add(fp).ok_or(Error::OverflowError)?;
+        }
+    }
+
+    if bracket_total_fp == 0 {
+        return Err(Error::DivisionByZero);
+    }
+
+    bracket_pool
+        .fixed_mul_floor(user_fp, bracket_total_fp)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// Percentile of a 0-indexed rank, 1-indexed so the single best contributor
+/// is "top 1/total_participants", not "top 0%"
+fn percentile_of_rank(rank: i128, total_participants: i128) -> Result<i128, Error> {
+    (rank + 1)
+        .fixed_div_floor(total_participants, SCALAR_7)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// Find which bracket a percentile falls into
+///
+/// Brackets are walked in order; the first one whose `top_percentile`
+/// covers `percentile` wins, so brackets must be ordered from smallest
+/// (most exclusive) to largest. The last bracket always matches, acting as
+/// the catch-all for everyone the tighter brackets above it didn't claim.
+fn bracket_for_percentile(brackets: &Vec<Bracket>, last_index: u32, percentile: i128) -> u32 {
+    for (i, bracket) in brackets.iter().enumerate() {
+        let i = i as u32;
+        if i == last_index {
+            return last_index;
+        }
+        if percentile <= bracket.top_percentile {
+            return i;
+        }
+    }
+    last_index
+}
+
+// ============================================================================
+// Epoch Bracket / Snapshot Configuration
+// ============================================================================
+
+/// Configure the tiered bracket split for an epoch
+///
+/// Must be called before the epoch is finalized so `claim_epoch_reward` can
+/// route winning-faction contributors into the right bracket. Only the
+/// contract admin may configure brackets.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn set_epoch_brackets(env: &Env, epoch: u32, brackets: Vec<Bracket>) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::set_epoch_brackets(env, epoch, &brackets);
+
+    Ok(())
+}
+
+/// Configure a top-contributor bonus tranche for an epoch's `settle_epoch_distribution`
+///
+/// `bonus_bps` (against `TRANCHE_DENOM_BPS` = 10_000) is carved off the
+/// distributable pool and split, pro-rata by FP, among just the `top_n`
+/// highest individual contributors to the winning faction - everyone
+/// (including those `top_n`) still splits the remaining pool pro-rata as
+/// before. Setting `bonus_bps` to 0 (the default) keeps the old behavior of
+/// a single pro-rata split over the whole pool.
+///
+/// Must be called before `settle_epoch_distribution` runs for the epoch -
+/// once settled, the tranche split is locked in along with everyone's share.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `InvalidAmount` - If `bonus_bps` is greater than `TRANCHE_DENOM_BPS` (10_000)
+pub(crate) fn set_top_contributor_bonus(
+    env: &Env,
+    epoch: u32,
+    top_n: u32,
+    bonus_bps: i128,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    if bonus_bps < 0 || bonus_bps > TRANCHE_DENOM_BPS {
+        return Err(Error::InvalidAmount);
+    }
+
+    storage::set_top_contributor_bonus(env, epoch, &TopContributorBonus { top_n, bonus_bps });
+
+    Ok(())
+}
+
+/// Record a winning faction's sorted FP snapshot at finalization
+///
+/// Called once per winning faction when an epoch is finalized so bracket
+/// ranks are fixed at that moment rather than recomputed from mutable,
+/// post-finalization state. `snapshot` must already be sorted by FP
+/// descending.
+pub(crate) fn record_faction_snapshot(
+    env: &Env,
+    epoch: u32,
+    faction: u32,
+    snapshot: Vec<(Address, i128)>,
+) {
+    storage::set_faction_snapshot(env, epoch, faction, &snapshot);
 }
 
 // ============================================================================
@@ -202,28 +1395,84 @@ pub(crate) fn get_claimable_amount(env: &Env, user: &Address, epoch: u32) -> i12
         return 0;
     }
 
-    // Get total fp for winning faction
-    let total_winning_fp = match epoch_info.faction_standings.get(winning_faction) {
-        Some(fp) => fp,
-        None => return 0,
+    // Get total fp for winning faction - same frozen-over-live preference as
+    // `settle_epoch_claim`, so a preview matches what claiming would pay.
+    let total_winning_fp = match storage::get_frozen_winning_fp(env, epoch) {
+        Some(frozen) => frozen,
+        None => match epoch_info.faction_standings.get(winning_faction) {
+            Some(fp) => fp,
+            None => return 0,
+        },
     };
 
     if total_winning_fp == 0 {
         return 0;
     }
 
-    // Calculate reward
-    match calculate_reward_share(
-        user_fp_contributed,
-        total_winning_fp,
-        epoch_info.reward_pool,
-    ) {
-        Ok(amount) => amount,
-        Err(_) => 0,
-    }
+    // Same time-weighted preference as `settle_epoch_claim`, so a preview
+    // matches what claiming would pay.
+    let weighted_override =
+        match weighted_fp_override(env, epoch, user, user_fp_contributed, epoch_info.end_time) {
+            Ok(o) => o,
+            Err(_) => return 0,
+        };
+
+    // Calculate reward against the post-commission distributable pool
+    let config = storage::get_config(env);
+    let distributable_pool = match net_of_commission(epoch_info.reward_pool, config.commission_rate) {
+        Ok(pool) => pool,
+        Err(_) => return 0,
+    };
+
+    let reward_amount = if storage::has_dust_settled(env, epoch) {
+        storage::get_settled_reward(env, epoch, user).unwrap_or(0)
+    } else {
+        match storage::get_epoch_brackets(env, epoch) {
+            Some(brackets) if !brackets.is_empty() => calculate_bracket_reward_share(
+                env,
+                epoch,
+                winning_faction,
+                user,
+                user_fp_contributed,
+                &brackets,
+                distributable_pool,
+            )
+            .unwrap_or(0),
+            _ => match weighted_override {
+                Some((weighted_user, weighted_total)) => {
+                    calculate_reward_share(weighted_user, weighted_total, distributable_pool).unwrap_or(0)
+                }
+                None => {
+                    calculate_reward_share(user_fp_contributed, total_winning_fp, distributable_pool)
+                        .unwrap_or(0)
+                }
+            },
+        }
+    };
+
+    // Reported claimable amount is the user's own share once backers (if
+    // any) are carved out - matches what `claim_epoch_reward` would pay them,
+    // apart from any final-claimant dust (`final_claimant_dust`), which is
+    // not included here.
+    split_reward_with_backers(env, epoch, user, user_fp_contributed, reward_amount)
+        .map(|(champion_share, _)| champion_share)
+        .unwrap_or(0)
 }
 
 /// Check if user has claimed rewards for an epoch
 pub(crate) fn has_claimed_rewards(env: &Env, user: &Address, epoch: u32) -> bool {
     storage::has_claimed(env, user, epoch)
 }
+
+/// Preview how much `user` would receive for `epoch` without claiming
+///
+/// This is a thin, explicitly-named front-end entry point over
+/// `get_claimable_amount`, which computes the payout the same way a claim
+/// does (floor-rounded pro-rata share against the winning-faction total
+/// frozen at `finalize_epoch`, or the time-weighted, bracket or settled
+/// amount where one applies). It exists so front-ends have a stable
+/// `preview_reward` name to call instead of reaching for the
+/// claim-settlement internals.
+pub(crate) fn preview_reward(env: &Env, user: &Address, epoch: u32) -> i128 {
+    get_claimable_amount(env, user, epoch)
+}
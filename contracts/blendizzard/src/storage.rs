@@ -1,5 +1,7 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Vec};
 
+use crate::game::WeightedFp;
+use crate::rewards::{Bracket, EpochRewardsSummary, TopContributorBonus};
 use crate::types::{Config, EpochInfo, EpochUser, GameSession, User};
 
 // ============================================================================
@@ -8,9 +10,105 @@ use crate::types::{Config, EpochInfo, EpochUser, GameSession, User};
 // Uses type-safe enum keys to prevent storage collisions and improve type safety
 //
 // Storage Types:
-// - Instance: Admin, Config, CurrentEpoch, Paused
-// - Persistent: User, Game
-// - Temporary: EpochUser, Epoch, Session, Claimed
+// - Instance: Admin, Config, CurrentEpoch, Paused, DisputeWindowLedgers, UnbondingEpochs, GameTimeout,
+//   GameCommissionBps, TreasuryFp
+// - Persistent: User, Game, Unlocking, ClaimRecord
+// - Temporary: EpochUser, Epoch, Session, Delegation, Backers, Dispute, GameStartTime, ExpiredGame, Pot,
+//   SettledReward, DustSettled, FactionDelegation, FrozenWinningFp, EpochCycleStatus, TopContributorBonus,
+//   EpochRewardsSummary, PlayerWeightedFp, FactionWeightedFp, FrozenWinningWeightedFp,
+//   EpochClaimantCount, RecordedDust
+
+/// One backer's delegation of FP to a target player for a given epoch, used
+/// by the faction-point backing subsystem (see `crate::game::delegate_fp`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    pub target: Address,
+    pub amount: i128,
+}
+
+/// Lifecycle state of an epoch, borrowed from Solana's bank lifecycle
+/// (open -> frozen -> rooted): `Open` accepts new/settling game sessions,
+/// `Frozen` rejects both while whatever's still in flight finishes out, and
+/// `Finalized` locks `EpochInfo.faction_standings`/`winning_faction` for
+/// good and unlocks reward claims. Epochs with no recorded state default to
+/// `Open` (see `get_epoch_state`), so epochs predating this lifecycle still
+/// behave as instant-settlement.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EpochState {
+    Open,
+    Frozen,
+    Finalized,
+}
+
+/// Progress of `cycle_epoch`'s bounded, resumable hand-off from one epoch to
+/// the next. `cycle_epoch` advances at most one phase per call and persists
+/// where it left off, so a failed swap or an oversized distribution doesn't
+/// force re-running finalization from scratch - the next call just resumes
+/// from whatever phase is recorded. `Active` is the steady state: either the
+/// epoch hasn't started cycling yet, or the previous cycle already finished
+/// and this is simply the epoch now open for play. Epochs with no recorded
+/// status default to `Active` (see `get_epoch_cycle_status`).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EpochCycleStatus {
+    Active,
+    Finalizing,
+    Swapping,
+    Distributing,
+}
+
+/// A user's compressed multi-epoch claim record, following Substrate's
+/// `claimed_rewards` range-tracking: `lowest_unclaimed_epoch` is a watermark
+/// below which every epoch is implicitly claimed, and `exceptions` holds any
+/// epoch at or above the watermark that's been claimed out of order (e.g. the
+/// user skipped an earlier epoch but claimed a later one). This replaces one
+/// `DataKey::Claimed(user, epoch)` entry per user per epoch with a single
+/// persistent entry per user. See `has_claimed`/`set_claimed`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRecord {
+    pub lowest_unclaimed_epoch: u32,
+    pub exceptions: Vec<u32>,
+}
+
+/// An N-player game session's single pot, recording every entrant's locked
+/// wager so `crate::game::end_game_multi` can split it by basis-point share
+/// without re-deriving who staked what. Kept alongside (not merged into)
+/// `GameSession`, which remains the two-player shape used by `start_game`/
+/// `end_game`. See `crate::game::start_game_multi`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PotSession {
+    pub game_id: Address,
+    pub epoch_id: u32,
+    pub entrants: Vec<(Address, i128)>,
+    pub resolved: bool,
+}
+
+/// A provisional game outcome awaiting either a successful dispute (which
+/// refunds both players and de-whitelists the game contract) or settlement
+/// via `crate::game::settle_game` once `challenge_deadline` passes
+/// undisputed. See `crate::game::end_game`/`dispute_outcome`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub player1_won: bool,
+    pub challenge_deadline: u32,
+}
+
+/// A bonded challenge against a pending `Dispute`, awaiting admin
+/// adjudication via `crate::game::resolve_dispute`. Exists only between a
+/// non-admin's `crate::game::dispute_outcome` call and that resolution -
+/// nothing is refunded, rewarded, or de-whitelisted until then.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub challenger: Address,
+    pub collateral: i128,
+    pub correct_result: bool,
+}
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -42,8 +140,141 @@ pub enum DataKey {
     /// Whitelisted game contracts - Game(game_address) -> bool (Persistent storage)
     Game(Address),
 
-    /// Reward claim tracking - Claimed(user_address, epoch_number) -> bool (Temporary storage)
-    Claimed(Address, u32),
+    /// Compressed multi-epoch claim record - ClaimRecord(user_address) -> ClaimRecord
+    /// (Persistent storage)
+    ClaimRecord(Address),
+
+    /// Running total of USDC paid out for an epoch - ClaimedTotal(epoch_number) -> i128 (Temporary storage)
+    ClaimedTotal(u32),
+
+    /// Commission withdrawal tracking - CommissionClaimed(epoch_number) -> bool (Temporary storage)
+    CommissionClaimed(u32),
+
+    /// Tiered bracket configuration for an epoch - EpochBrackets(epoch_number) -> Vec<Bracket> (Temporary storage)
+    EpochBrackets(u32),
+
+    /// Top-contributor bonus tranche configuration for an epoch -
+    /// TopContributorBonus(epoch_number) -> TopContributorBonus (Temporary storage)
+    TopContributorBonus(u32),
+
+    /// Running summary of an epoch's reward distribution, built up one claim
+    /// at a time - EpochRewardsSummary(epoch_number) -> EpochRewardsSummary
+    /// (Temporary storage)
+    EpochRewardsSummary(u32),
+
+    /// Sorted FP snapshot of a faction's contributors at finalization -
+    /// FactionSnapshot(epoch_number, faction_id) -> Vec<(Address, i128)>, sorted by FP descending
+    /// (Temporary storage)
+    FactionSnapshot(u32, u32),
+
+    /// Dust-sweep tracking - DustSwept(epoch_number) -> bool (Temporary storage)
+    DustSwept(u32),
+
+    /// Count of distinct users who have claimed an epoch's reward so far,
+    /// used to detect the winning faction's final claimant -
+    /// EpochClaimantCount(epoch_number) -> u32 (Temporary storage)
+    EpochClaimantCount(u32),
+
+    /// Dust amount explicitly recorded as swept or auto-distributed for an
+    /// epoch, so `claimed_total + recorded_dust == distributable_pool` is
+    /// auditable - RecordedDust(epoch_number) -> i128 (Temporary storage)
+    RecordedDust(u32),
+
+    /// One backer's active delegation - Delegation(epoch_number, backer_address) -> Delegation
+    /// (Temporary storage)
+    Delegation(u32, Address),
+
+    /// Index of backers currently delegating to a target -
+    /// Backers(epoch_number, target_address) -> Vec<Address> (Temporary storage)
+    Backers(u32, Address),
+
+    /// Epoch lifecycle state - EpochLifecycle(epoch_number) -> EpochState
+    /// (Temporary storage)
+    EpochLifecycle(u32),
+
+    /// Provisional outcome awaiting dispute/settlement -
+    /// Dispute(session_id) -> Dispute (Temporary storage)
+    Dispute(u32),
+
+    /// Bonded challenge awaiting admin adjudication -
+    /// Challenge(session_id) -> Challenge (Temporary storage)
+    Challenge(u32),
+
+    /// Configured dispute window, in ledgers - singleton, defaults to 0
+    /// (instant settlement) when unset (Instance storage)
+    DisputeWindowLedgers,
+
+    /// A player's queued unbonding chunks - Unlocking(player_address) ->
+    /// Vec<(amount, available_epoch)> (Persistent storage)
+    Unlocking(Address),
+
+    /// Configured unbonding period, in epochs - singleton, defaults to 0
+    /// (instant release) when unset (Instance storage)
+    UnbondingEpochs,
+
+    /// Ledger timestamp a session's wagers were locked at -
+    /// GameStartTime(session_id) -> u64 (Temporary storage)
+    GameStartTime(u32),
+
+    /// Marks a session as closed via `resolve_expired_game`, so the real
+    /// game contract's later `end_game` can't double-spend it -
+    /// ExpiredGame(session_id) -> bool (Temporary storage)
+    ExpiredGame(u32),
+
+    /// Configured game timeout, in seconds - singleton, defaults to 0
+    /// (unconfigured, so abandoned games stay locked forever, same as
+    /// before `resolve_expired_game` existed) when unset (Instance storage)
+    GameTimeout,
+
+    /// N-player game session pot - Pot(session_id) -> PotSession
+    /// (Temporary storage)
+    Pot(u32),
+
+    /// Deterministically settled per-player reward, dust included -
+    /// SettledReward(epoch_number, user_address) -> i128 (Temporary storage)
+    SettledReward(u32, Address),
+
+    /// Whether `settle_epoch_distribution` has already run for an epoch -
+    /// DustSettled(epoch_number) -> bool (Temporary storage)
+    DustSettled(u32),
+
+    /// Protocol commission skimmed from every game's pot at `end_game`/
+    /// `end_game_multi` time, in basis points (10_000 = 100%) - singleton,
+    /// defaults to 0 (nothing skimmed) when unset (Instance storage)
+    GameCommissionBps,
+
+    /// Treasury's accumulated, claimable FP skimmed from game pots -
+    /// singleton running total (Instance storage)
+    TreasuryFp,
+
+    /// A delegator's active direct-to-faction delegation amount -
+    /// FactionDelegation(epoch_number, delegator_address) -> i128
+    /// (Temporary storage)
+    FactionDelegation(u32, Address),
+
+    /// The winning faction's total FP standing, frozen at the moment
+    /// `finalize_epoch` runs - FrozenWinningFp(epoch_number) -> i128
+    /// (Temporary storage)
+    FrozenWinningFp(u32),
+
+    /// `cycle_epoch`'s current phase for an epoch -
+    /// EpochCycleStatus(epoch_number) -> EpochCycleStatus (Temporary storage)
+    EpochCycleStatus(u32),
+
+    /// A player's time-weighted FP accumulator for an epoch -
+    /// PlayerWeightedFp(epoch_number, player_address) -> WeightedFp
+    /// (Temporary storage)
+    PlayerWeightedFp(u32, Address),
+
+    /// A faction's time-weighted total FP accumulator for an epoch -
+    /// FactionWeightedFp(epoch_number, faction_id) -> WeightedFp
+    /// (Temporary storage)
+    FactionWeightedFp(u32, u32),
+
+    /// The winning faction's time-weighted FP total, brought forward to
+    /// `end_time` and frozen at the moment `finalize_epoch` runs -
+    /// FrozenWinningWeightedFp(epoch_number) -> i128 (Temporary storage)
+    FrozenWinningWeightedFp(u32),
 }
 
 // ============================================================================
@@ -209,25 +440,716 @@ pub(crate) fn remove_game_from_whitelist(env: &Env, game_id: &Address) {
         .remove(&DataKey::Game(game_id.clone()));
 }
 
+/// Get a user's compressed claim record, defaulting to an empty one (nothing
+/// claimed yet) if they've never claimed anything
+fn get_claim_record(env: &Env, user: &Address) -> ClaimRecord {
+    let key = DataKey::ClaimRecord(user.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_claim_record_ttl(env, user);
+    }
+    result.unwrap_or(ClaimRecord {
+        lowest_unclaimed_epoch: 0,
+        exceptions: Vec::new(env),
+    })
+}
+
+/// Set a user's compressed claim record
+fn set_claim_record(env: &Env, user: &Address, record: &ClaimRecord) {
+    let key = DataKey::ClaimRecord(user.clone());
+    env.storage().persistent().set(&key, record);
+    extend_claim_record_ttl(env, user);
+}
+
+/// Apply `set_claimed` for every epoch in `epochs` against a single
+/// in-memory `ClaimRecord`, persisting it with one storage write instead of
+/// one per epoch. Used by `crate::rewards::claim_all` to settle a whole
+/// range of epochs in one transaction without the per-epoch storage churn
+/// the old `DataKey::Claimed(user, epoch)` scheme had.
+pub(crate) fn set_claimed_many(env: &Env, user: &Address, epochs: &Vec<u32>) {
+    if epochs.is_empty() {
+        return;
+    }
+
+    let mut record = get_claim_record(env, user);
+
+    for epoch in epochs.iter() {
+        if epoch < record.lowest_unclaimed_epoch {
+            continue; // Already claimed; idempotent.
+        }
+
+        if epoch > record.lowest_unclaimed_epoch {
+            if !record.exceptions.iter().any(|claimed| claimed == epoch) {
+                record.exceptions.push_back(epoch);
+            }
+            continue;
+        }
+
+        record.lowest_unclaimed_epoch += 1;
+        loop {
+            let mut absorbed = false;
+            let mut remaining: Vec<u32> = Vec::new(env);
+            for claimed in record.exceptions.iter() {
+                if claimed == record.lowest_unclaimed_epoch {
+                    absorbed = true;
+                } else {
+                    remaining.push_back(claimed);
+                }
+            }
+            record.exceptions = remaining;
+            if !absorbed {
+                break;
+            }
+            record.lowest_unclaimed_epoch += 1;
+        }
+    }
+
+    set_claim_record(env, user, &record);
+}
+
 /// Check if user has claimed rewards for an epoch
 pub(crate) fn has_claimed(env: &Env, user: &Address, epoch: u32) -> bool {
-    let key = DataKey::Claimed(user.clone(), epoch);
+    let record = get_claim_record(env, user);
+    if epoch < record.lowest_unclaimed_epoch {
+        return true;
+    }
+    record.exceptions.iter().any(|claimed| claimed == epoch)
+}
+
+/// Mark rewards as claimed for user and epoch
+///
+/// Epochs claimed in order simply advance `lowest_unclaimed_epoch` (and then
+/// absorb any later exceptions that are now contiguous with it). An epoch
+/// claimed out of order - e.g. the user skips an earlier epoch but claims a
+/// later one - is recorded as an exception instead, so it doesn't get
+/// re-claimed once the watermark eventually catches up to it.
+pub(crate) fn set_claimed(env: &Env, user: &Address, epoch: u32) {
+    let mut record = get_claim_record(env, user);
+
+    if epoch < record.lowest_unclaimed_epoch {
+        return; // Already claimed; idempotent.
+    }
+
+    if epoch > record.lowest_unclaimed_epoch {
+        if !record.exceptions.iter().any(|claimed| claimed == epoch) {
+            record.exceptions.push_back(epoch);
+        }
+        set_claim_record(env, user, &record);
+        return;
+    }
+
+    // epoch == lowest_unclaimed_epoch: advance the watermark, then keep
+    // absorbing any exceptions that are now contiguous with it.
+    record.lowest_unclaimed_epoch += 1;
+    loop {
+        let mut absorbed = false;
+        let mut remaining: Vec<u32> = Vec::new(env);
+        for claimed in record.exceptions.iter() {
+            if claimed == record.lowest_unclaimed_epoch {
+                absorbed = true;
+            } else {
+                remaining.push_back(claimed);
+            }
+        }
+        record.exceptions = remaining;
+        if !absorbed {
+            break;
+        }
+        record.lowest_unclaimed_epoch += 1;
+    }
+
+    set_claim_record(env, user, &record);
+}
+
+/// Get the running total of USDC claimed so far for an epoch
+pub(crate) fn get_claimed_total(env: &Env, epoch: u32) -> i128 {
+    let key = DataKey::ClaimedTotal(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_claimed_total_ttl(env, epoch);
+    }
+    result.unwrap_or(0)
+}
+
+/// Increment the running total of USDC claimed for an epoch
+pub(crate) fn add_claimed_total(env: &Env, epoch: u32, amount: i128) {
+    let key = DataKey::ClaimedTotal(epoch);
+    let new_total = get_claimed_total(env, epoch) + amount;
+    env.storage().temporary().set(&key, &new_total);
+    extend_claimed_total_ttl(env, epoch);
+}
+
+/// Check if the treasury has withdrawn commission for an epoch
+pub(crate) fn has_commission_claimed(env: &Env, epoch: u32) -> bool {
+    let key = DataKey::CommissionClaimed(epoch);
     let result: Option<bool> = env.storage().temporary().get(&key);
     if let Some(true) = result {
-        extend_claimed_ttl(env, user, epoch);
+        extend_commission_claimed_ttl(env, epoch);
         true
     } else {
         false
     }
 }
 
-/// Mark rewards as claimed for user and epoch
-pub(crate) fn set_claimed(env: &Env, user: &Address, epoch: u32) {
-    let key = DataKey::Claimed(user.clone(), epoch);
+/// Mark commission as withdrawn for an epoch
+pub(crate) fn set_commission_claimed(env: &Env, epoch: u32) {
+    let key = DataKey::CommissionClaimed(epoch);
+    env.storage().temporary().set(&key, &true);
+    extend_commission_claimed_ttl(env, epoch);
+}
+
+/// Get the tiered bracket configuration for an epoch, if one was set
+pub(crate) fn get_epoch_brackets(env: &Env, epoch: u32) -> Option<Vec<Bracket>> {
+    let key = DataKey::EpochBrackets(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_brackets_ttl(env, epoch);
+    }
+    result
+}
+
+/// Set the tiered bracket configuration for an epoch
+pub(crate) fn set_epoch_brackets(env: &Env, epoch: u32, brackets: &Vec<Bracket>) {
+    let key = DataKey::EpochBrackets(epoch);
+    env.storage().temporary().set(&key, brackets);
+    extend_epoch_brackets_ttl(env, epoch);
+}
+
+/// Get the top-contributor bonus tranche configuration for an epoch, if one was set
+pub(crate) fn get_top_contributor_bonus(env: &Env, epoch: u32) -> Option<TopContributorBonus> {
+    let key = DataKey::TopContributorBonus(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_top_contributor_bonus_ttl(env, epoch);
+    }
+    result
+}
+
+/// Set the top-contributor bonus tranche configuration for an epoch
+pub(crate) fn set_top_contributor_bonus(env: &Env, epoch: u32, bonus: &TopContributorBonus) {
+    let key = DataKey::TopContributorBonus(epoch);
+    env.storage().temporary().set(&key, bonus);
+    extend_top_contributor_bonus_ttl(env, epoch);
+}
+
+/// Get an epoch's running rewards summary, if any claims have settled yet
+pub(crate) fn get_epoch_rewards_summary(env: &Env, epoch: u32) -> Option<EpochRewardsSummary> {
+    let key = DataKey::EpochRewardsSummary(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_rewards_summary_ttl(env, epoch);
+    }
+    result
+}
+
+/// Set an epoch's running rewards summary
+pub(crate) fn set_epoch_rewards_summary(env: &Env, epoch: u32, summary: &EpochRewardsSummary) {
+    let key = DataKey::EpochRewardsSummary(epoch);
+    env.storage().temporary().set(&key, summary);
+    extend_epoch_rewards_summary_ttl(env, epoch);
+}
+
+/// Get the sorted FP snapshot recorded for a faction at finalization
+pub(crate) fn get_faction_snapshot(env: &Env, epoch: u32, faction: u32) -> Option<Vec<(Address, i128)>> {
+    let key = DataKey::FactionSnapshot(epoch, faction);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_faction_snapshot_ttl(env, epoch, faction);
+    }
+    result
+}
+
+/// Record a faction's sorted FP snapshot at finalization
+pub(crate) fn set_faction_snapshot(env: &Env, epoch: u32, faction: u32, snapshot: &Vec<(Address, i128)>) {
+    let key = DataKey::FactionSnapshot(epoch, faction);
+    env.storage().temporary().set(&key, snapshot);
+    extend_faction_snapshot_ttl(env, epoch, faction);
+}
+
+/// Check if an epoch's leftover dust has already been swept
+pub(crate) fn has_dust_swept(env: &Env, epoch: u32) -> bool {
+    let key = DataKey::DustSwept(epoch);
+    let result: Option<bool> = env.storage().temporary().get(&key);
+    if let Some(true) = result {
+        extend_dust_swept_ttl(env, epoch);
+        true
+    } else {
+        false
+    }
+}
+
+/// Mark an epoch's leftover dust as swept
+pub(crate) fn set_dust_swept(env: &Env, epoch: u32) {
+    let key = DataKey::DustSwept(epoch);
+    env.storage().temporary().set(&key, &true);
+    extend_dust_swept_ttl(env, epoch);
+}
+
+/// Number of distinct users who have claimed an epoch's reward so far
+pub(crate) fn get_claimant_count(env: &Env, epoch: u32) -> u32 {
+    let key = DataKey::EpochClaimantCount(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_claimant_count_ttl(env, epoch);
+    }
+    result.unwrap_or(0)
+}
+
+/// Record one more distinct claimant against an epoch's running count
+///
+/// # Returns
+/// The updated count, including this claimant
+pub(crate) fn increment_claimant_count(env: &Env, epoch: u32) -> u32 {
+    let count = get_claimant_count(env, epoch) + 1;
+    let key = DataKey::EpochClaimantCount(epoch);
+    env.storage().temporary().set(&key, &count);
+    extend_claimant_count_ttl(env, epoch);
+    count
+}
+
+/// The dust amount already recorded as swept/distributed for an epoch, if any
+pub(crate) fn get_recorded_dust(env: &Env, epoch: u32) -> Option<i128> {
+    let key = DataKey::RecordedDust(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_recorded_dust_ttl(env, epoch);
+    }
+    result
+}
+
+/// Record the dust amount swept or auto-distributed for an epoch
+pub(crate) fn set_recorded_dust(env: &Env, epoch: u32, dust: i128) {
+    let key = DataKey::RecordedDust(epoch);
+    env.storage().temporary().set(&key, &dust);
+    extend_recorded_dust_ttl(env, epoch);
+}
+
+/// Get a backer's active delegation for an epoch, if any
+pub(crate) fn get_delegation(env: &Env, epoch: u32, backer: &Address) -> Option<Delegation> {
+    let key = DataKey::Delegation(epoch, backer.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_delegation_ttl(env, epoch, backer);
+    }
+    result
+}
+
+/// Set a backer's active delegation for an epoch
+pub(crate) fn set_delegation(env: &Env, epoch: u32, backer: &Address, data: &Delegation) {
+    let key = DataKey::Delegation(epoch, backer.clone());
+    env.storage().temporary().set(&key, data);
+    extend_delegation_ttl(env, epoch, backer);
+}
+
+/// Check if a backer has an active delegation for an epoch
+pub(crate) fn has_delegation(env: &Env, epoch: u32, backer: &Address) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::Delegation(epoch, backer.clone()))
+}
+
+/// Remove a backer's delegation for an epoch (on undelegate)
+pub(crate) fn remove_delegation(env: &Env, epoch: u32, backer: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::Delegation(epoch, backer.clone()));
+}
+
+/// Get the index of backers currently delegating to a target for an epoch
+pub(crate) fn get_backers(env: &Env, epoch: u32, target: &Address) -> Vec<Address> {
+    let key = DataKey::Backers(epoch, target.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_backers_ttl(env, epoch, target);
+    }
+    result.unwrap_or(Vec::new(env))
+}
+
+/// Set the index of backers currently delegating to a target for an epoch
+pub(crate) fn set_backers(env: &Env, epoch: u32, target: &Address, backers: &Vec<Address>) {
+    let key = DataKey::Backers(epoch, target.clone());
+    env.storage().temporary().set(&key, backers);
+    extend_backers_ttl(env, epoch, target);
+}
+
+/// Get a delegator's active direct-to-faction delegation amount for an epoch, if any
+pub(crate) fn get_faction_delegation(env: &Env, epoch: u32, delegator: &Address) -> Option<i128> {
+    let key = DataKey::FactionDelegation(epoch, delegator.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_faction_delegation_ttl(env, epoch, delegator);
+    }
+    result
+}
+
+/// Set a delegator's active direct-to-faction delegation amount for an epoch
+pub(crate) fn set_faction_delegation(env: &Env, epoch: u32, delegator: &Address, amount: i128) {
+    let key = DataKey::FactionDelegation(epoch, delegator.clone());
+    env.storage().temporary().set(&key, &amount);
+    extend_faction_delegation_ttl(env, epoch, delegator);
+}
+
+/// Check if a delegator has an active direct-to-faction delegation for an epoch
+pub(crate) fn has_faction_delegation(env: &Env, epoch: u32, delegator: &Address) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::FactionDelegation(epoch, delegator.clone()))
+}
+
+/// Remove a delegator's direct-to-faction delegation for an epoch (on undelegate)
+pub(crate) fn remove_faction_delegation(env: &Env, epoch: u32, delegator: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::FactionDelegation(epoch, delegator.clone()));
+}
+
+/// Get an epoch's lifecycle state. Epochs with nothing recorded yet default
+/// to `Open`, so epochs created before this lifecycle tracking existed keep
+/// behaving as instant-settlement.
+pub(crate) fn get_epoch_state(env: &Env, epoch: u32) -> EpochState {
+    let key = DataKey::EpochLifecycle(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_lifecycle_ttl(env, epoch);
+    }
+    result.unwrap_or(EpochState::Open)
+}
+
+/// Set an epoch's lifecycle state
+pub(crate) fn set_epoch_state(env: &Env, epoch: u32, state: EpochState) {
+    let key = DataKey::EpochLifecycle(epoch);
+    env.storage().temporary().set(&key, &state);
+    extend_epoch_lifecycle_ttl(env, epoch);
+}
+
+/// Check that an epoch is still Open, return error if frozen/finalized
+/// Call this at the start of any function that starts or settles a game
+pub(crate) fn require_epoch_open(env: &Env, epoch: u32) -> Result<(), crate::errors::Error> {
+    if get_epoch_state(env, epoch) == EpochState::Open {
+        Ok(())
+    } else {
+        Err(crate::errors::Error::EpochNotOpen)
+    }
+}
+
+/// Get an epoch's `cycle_epoch` phase
+pub(crate) fn get_epoch_cycle_status(env: &Env, epoch: u32) -> EpochCycleStatus {
+    let key = DataKey::EpochCycleStatus(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_epoch_cycle_status_ttl(env, epoch);
+    }
+    result.unwrap_or(EpochCycleStatus::Active)
+}
+
+/// Set an epoch's `cycle_epoch` phase
+pub(crate) fn set_epoch_cycle_status(env: &Env, epoch: u32, status: EpochCycleStatus) {
+    let key = DataKey::EpochCycleStatus(epoch);
+    env.storage().temporary().set(&key, &status);
+    extend_epoch_cycle_status_ttl(env, epoch);
+}
+
+/// Get a session's pending dispute, if any
+pub(crate) fn get_dispute(env: &Env, session_id: u32) -> Option<Dispute> {
+    let key = DataKey::Dispute(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_dispute_ttl(env, session_id);
+    }
+    result
+}
+
+/// Record a session's provisional outcome as a pending dispute
+pub(crate) fn set_dispute(env: &Env, session_id: u32, data: &Dispute) {
+    let key = DataKey::Dispute(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_dispute_ttl(env, session_id);
+}
+
+/// Check if a session has a pending dispute
+pub(crate) fn has_dispute(env: &Env, session_id: u32) -> bool {
+    env.storage().temporary().has(&DataKey::Dispute(session_id))
+}
+
+/// Clear a session's pending dispute (on successful dispute or settlement)
+pub(crate) fn remove_dispute(env: &Env, session_id: u32) {
+    env.storage().temporary().remove(&DataKey::Dispute(session_id));
+}
+
+/// Get a session's pending bonded challenge, if any
+pub(crate) fn get_challenge(env: &Env, session_id: u32) -> Option<Challenge> {
+    let key = DataKey::Challenge(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_challenge_ttl(env, session_id);
+    }
+    result
+}
+
+/// Record a non-admin's bonded challenge against a pending dispute
+pub(crate) fn set_challenge(env: &Env, session_id: u32, data: &Challenge) {
+    let key = DataKey::Challenge(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_challenge_ttl(env, session_id);
+}
+
+/// Check if a session has a pending bonded challenge
+pub(crate) fn has_challenge(env: &Env, session_id: u32) -> bool {
+    env.storage().temporary().has(&DataKey::Challenge(session_id))
+}
+
+/// Clear a session's pending bonded challenge (once adjudicated)
+pub(crate) fn remove_challenge(env: &Env, session_id: u32) {
+    env.storage().temporary().remove(&DataKey::Challenge(session_id));
+}
+
+/// Get the configured dispute window, in ledgers. Defaults to 0 (instant
+/// settlement) when never configured.
+pub(crate) fn get_dispute_window_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeWindowLedgers)
+        .unwrap_or(0)
+}
+
+/// Set the configured dispute window, in ledgers
+pub(crate) fn set_dispute_window_ledgers(env: &Env, window_ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputeWindowLedgers, &window_ledgers);
+}
+
+/// Get a player's queued unbonding chunks, each an `(amount, available_epoch)`
+/// pair. Empty if the player has never had FP released into the queue.
+pub(crate) fn get_unlocking_chunks(env: &Env, player: &Address) -> Vec<(i128, u32)> {
+    let key = DataKey::Unlocking(player.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_unlocking_ttl(env, player);
+    }
+    result.unwrap_or(Vec::new(env))
+}
+
+/// Set a player's queued unbonding chunks
+pub(crate) fn set_unlocking_chunks(env: &Env, player: &Address, chunks: &Vec<(i128, u32)>) {
+    let key = DataKey::Unlocking(player.clone());
+    env.storage().persistent().set(&key, chunks);
+    extend_unlocking_ttl(env, player);
+}
+
+/// Get the configured unbonding period, in epochs. Defaults to 0 (instant
+/// release) when never configured, so existing games keep working.
+pub(crate) fn get_unbonding_epochs(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::UnbondingEpochs)
+        .unwrap_or(0)
+}
+
+/// Set the configured unbonding period, in epochs
+pub(crate) fn set_unbonding_epochs(env: &Env, epochs: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::UnbondingEpochs, &epochs);
+}
+
+/// Get the ledger timestamp a session's wagers were locked at
+pub(crate) fn get_game_start_time(env: &Env, session_id: u32) -> Option<u64> {
+    let key = DataKey::GameStartTime(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_game_start_time_ttl(env, session_id);
+    }
+    result
+}
+
+/// Record the ledger timestamp a session's wagers were locked at
+pub(crate) fn set_game_start_time(env: &Env, session_id: u32, timestamp: u64) {
+    let key = DataKey::GameStartTime(session_id);
+    env.storage().temporary().set(&key, &timestamp);
+    extend_game_start_time_ttl(env, session_id);
+}
+
+/// Check if a session has been closed out via `resolve_expired_game`
+pub(crate) fn has_expired_game(env: &Env, session_id: u32) -> bool {
     env.storage()
         .temporary()
-        .set(&key, &true);
-    extend_claimed_ttl(env, user, epoch);
+        .has(&DataKey::ExpiredGame(session_id))
+}
+
+/// Mark a session as closed via `resolve_expired_game`
+pub(crate) fn set_expired_game(env: &Env, session_id: u32) {
+    let key = DataKey::ExpiredGame(session_id);
+    env.storage().temporary().set(&key, &true);
+    extend_expired_game_ttl(env, session_id);
+}
+
+/// Get the configured game timeout, in seconds. Defaults to 0 (unconfigured)
+/// when never set, which keeps `resolve_expired_game` disabled so abandoned
+/// games stay locked exactly like before this existed.
+pub(crate) fn get_game_timeout(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::GameTimeout)
+        .unwrap_or(0)
+}
+
+/// Set the configured game timeout, in seconds
+pub(crate) fn set_game_timeout(env: &Env, timeout_seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::GameTimeout, &timeout_seconds);
+}
+
+/// Get the configured protocol commission, in basis points. Defaults to 0
+/// (nothing skimmed) when never configured.
+pub(crate) fn get_game_commission_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::GameCommissionBps)
+        .unwrap_or(0)
+}
+
+/// Set the configured protocol commission, in basis points
+pub(crate) fn set_game_commission_bps(env: &Env, bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::GameCommissionBps, &bps);
+}
+
+/// Get the treasury's accumulated, claimable FP skimmed from game pots.
+/// Defaults to 0 when no commission has ever been skimmed.
+pub(crate) fn get_treasury_fp(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::TreasuryFp).unwrap_or(0)
+}
+
+/// Credit `amount` of freshly-skimmed commission to the treasury's running FP balance
+pub(crate) fn add_treasury_fp(env: &Env, amount: i128) {
+    let balance = get_treasury_fp(env) + amount;
+    env.storage().instance().set(&DataKey::TreasuryFp, &balance);
+}
+
+/// Get an N-player session's pot
+pub(crate) fn get_pot(env: &Env, session_id: u32) -> Option<PotSession> {
+    let key = DataKey::Pot(session_id);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_pot_ttl(env, session_id);
+    }
+    result
+}
+
+/// Set an N-player session's pot
+pub(crate) fn set_pot(env: &Env, session_id: u32, data: &PotSession) {
+    let key = DataKey::Pot(session_id);
+    env.storage().temporary().set(&key, data);
+    extend_pot_ttl(env, session_id);
+}
+
+/// Check if an N-player session's pot exists
+pub(crate) fn has_pot(env: &Env, session_id: u32) -> bool {
+    env.storage().temporary().has(&DataKey::Pot(session_id))
+}
+
+/// Get a player's deterministically settled reward for an epoch, if
+/// `settle_epoch_distribution` has assigned one
+pub(crate) fn get_settled_reward(env: &Env, epoch: u32, user: &Address) -> Option<i128> {
+    let key = DataKey::SettledReward(epoch, user.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_settled_reward_ttl(env, epoch, user);
+    }
+    result
+}
+
+/// Set a player's deterministically settled reward for an epoch
+pub(crate) fn set_settled_reward(env: &Env, epoch: u32, user: &Address, amount: i128) {
+    let key = DataKey::SettledReward(epoch, user.clone());
+    env.storage().temporary().set(&key, &amount);
+    extend_settled_reward_ttl(env, epoch, user);
+}
+
+/// Check if `settle_epoch_distribution` has already run for an epoch
+pub(crate) fn has_dust_settled(env: &Env, epoch: u32) -> bool {
+    env.storage().temporary().has(&DataKey::DustSettled(epoch))
+}
+
+/// Mark an epoch's distribution as settled
+pub(crate) fn set_dust_settled(env: &Env, epoch: u32) {
+    let key = DataKey::DustSettled(epoch);
+    env.storage().temporary().set(&key, &true);
+    extend_dust_settled_ttl(env, epoch);
+}
+
+/// Get the winning faction's total FP standing as frozen by `finalize_epoch`,
+/// if this epoch was finalized after that freeze was introduced
+pub(crate) fn get_frozen_winning_fp(env: &Env, epoch: u32) -> Option<i128> {
+    let key = DataKey::FrozenWinningFp(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_frozen_winning_fp_ttl(env, epoch);
+    }
+    result
+}
+
+/// Freeze the winning faction's total FP standing for an epoch, read once at
+/// `finalize_epoch` time so later storage writes can never dilute it
+pub(crate) fn set_frozen_winning_fp(env: &Env, epoch: u32, total_fp: i128) {
+    let key = DataKey::FrozenWinningFp(epoch);
+    env.storage().temporary().set(&key, &total_fp);
+    extend_frozen_winning_fp_ttl(env, epoch);
+}
+
+/// Get a player's time-weighted FP accumulator for an epoch, if it's ever been touched
+pub(crate) fn get_player_weighted_fp(env: &Env, epoch: u32, player: &Address) -> Option<WeightedFp> {
+    let key = DataKey::PlayerWeightedFp(epoch, player.clone());
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_player_weighted_fp_ttl(env, epoch, player);
+    }
+    result
+}
+
+/// Set a player's time-weighted FP accumulator for an epoch
+pub(crate) fn set_player_weighted_fp(env: &Env, epoch: u32, player: &Address, weighted: &WeightedFp) {
+    let key = DataKey::PlayerWeightedFp(epoch, player.clone());
+    env.storage().temporary().set(&key, weighted);
+    extend_player_weighted_fp_ttl(env, epoch, player);
+}
+
+/// Get a faction's time-weighted total FP accumulator for an epoch, if it's ever been touched
+pub(crate) fn get_faction_weighted_fp(env: &Env, epoch: u32, faction: u32) -> Option<WeightedFp> {
+    let key = DataKey::FactionWeightedFp(epoch, faction);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_faction_weighted_fp_ttl(env, epoch, faction);
+    }
+    result
+}
+
+/// Set a faction's time-weighted total FP accumulator for an epoch
+pub(crate) fn set_faction_weighted_fp(env: &Env, epoch: u32, faction: u32, weighted: &WeightedFp) {
+    let key = DataKey::FactionWeightedFp(epoch, faction);
+    env.storage().temporary().set(&key, weighted);
+    extend_faction_weighted_fp_ttl(env, epoch, faction);
+}
+
+/// Get the winning faction's time-weighted FP total, frozen at `finalize_epoch`
+/// time and brought forward to `end_time`
+pub(crate) fn get_frozen_winning_weighted_fp(env: &Env, epoch: u32) -> Option<i128> {
+    let key = DataKey::FrozenWinningWeightedFp(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_frozen_winning_weighted_fp_ttl(env, epoch);
+    }
+    result
+}
+
+/// Freeze the winning faction's time-weighted FP total for an epoch
+pub(crate) fn set_frozen_winning_weighted_fp(env: &Env, epoch: u32, total_weighted_fp: i128) {
+    let key = DataKey::FrozenWinningWeightedFp(epoch);
+    env.storage().temporary().set(&key, &total_weighted_fp);
+    extend_frozen_winning_weighted_fp_ttl(env, epoch);
 }
 
 // ============================================================================
@@ -236,12 +1158,12 @@ pub(crate) fn set_claimed(env: &Env, user: &Address, epoch: u32) {
 // TTL (Time To Live) management ensures data doesn't expire unexpectedly
 // Based on Soroban best practices:
 // - Instance storage: Tied to contract lifetime (Admin, Config, CurrentEpoch, Paused)
-// - Persistent storage: Cross-epoch data (User, Game whitelist) - extends to 30 days when accessed
+// - Persistent storage: Cross-epoch data (User, Game whitelist, Unlocking queue) - extends to 30 days when accessed
 // - Temporary storage: Epoch-specific data (EpochUser, Epoch, Claimed, Session) - 30 days from last interaction
 //
 // Storage Type Summary:
 // - Instance: Config-type variables that persist for contract lifetime
-// - Persistent: User data and game whitelist that must survive across epochs
+// - Persistent: User data, game whitelist, and unbonding queues that must survive across epochs
 // - Temporary: Epoch-specific data that expires 30 days after last access
 
 /// TTL thresholds and extensions (in ledgers, ~5 seconds per ledger)
@@ -280,11 +1202,199 @@ pub(crate) fn extend_epoch_ttl(env: &Env, epoch: u32) {
     );
 }
 
-/// Extend TTL for claimed rewards data (temporary storage)
-/// Should be called whenever claim data is written
-pub(crate) fn extend_claimed_ttl(env: &Env, user: &Address, epoch: u32) {
+/// Extend TTL for a user's compressed claim record (persistent storage)
+/// Should be called whenever the claim record is read/written
+pub(crate) fn extend_claim_record_ttl(env: &Env, user: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::ClaimRecord(user.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for claimed-total accumulator data (temporary storage)
+/// Should be called whenever the claimed-total accumulator is read/written
+pub(crate) fn extend_claimed_total_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::ClaimedTotal(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for commission claim tracking data (temporary storage)
+/// Should be called whenever commission claim data is read/written
+pub(crate) fn extend_commission_claimed_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::CommissionClaimed(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for tiered bracket configuration data (temporary storage)
+/// Should be called whenever an epoch's bracket configuration is read/written
+pub(crate) fn extend_epoch_brackets_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochBrackets(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for top-contributor bonus tranche configuration data (temporary storage)
+/// Should be called whenever an epoch's bonus configuration is read/written
+pub(crate) fn extend_top_contributor_bonus_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::TopContributorBonus(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for an epoch's running rewards summary (temporary storage)
+/// Should be called whenever the summary is read/written
+pub(crate) fn extend_epoch_rewards_summary_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochRewardsSummary(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for faction FP snapshot data (temporary storage)
+/// Should be called whenever a faction's finalization snapshot is read/written
+pub(crate) fn extend_faction_snapshot_ttl(env: &Env, epoch: u32, faction: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::FactionSnapshot(epoch, faction),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for dust-sweep tracking data (temporary storage)
+/// Should be called whenever dust-sweep data is read/written
+pub(crate) fn extend_dust_swept_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::DustSwept(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for an epoch's claimant-count data (temporary storage)
+/// Should be called whenever the claimant count is read/written
+pub(crate) fn extend_claimant_count_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochClaimantCount(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for an epoch's recorded-dust data (temporary storage)
+/// Should be called whenever the recorded dust amount is read/written
+pub(crate) fn extend_recorded_dust_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::RecordedDust(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for delegation data (temporary storage)
+/// Should be called whenever a backer's delegation is read/written
+pub(crate) fn extend_delegation_ttl(env: &Env, epoch: u32, backer: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::Delegation(epoch, backer.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for the backers-of-a-target index (temporary storage)
+/// Should be called whenever a target's backer index is read/written
+pub(crate) fn extend_backers_ttl(env: &Env, epoch: u32, target: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::Backers(epoch, target.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for direct-to-faction delegation data (temporary storage)
+/// Should be called whenever a delegator's faction delegation is read/written
+pub(crate) fn extend_faction_delegation_ttl(env: &Env, epoch: u32, delegator: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::FactionDelegation(epoch, delegator.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for epoch lifecycle state (temporary storage)
+/// Should be called whenever an epoch's lifecycle state is read/written
+pub(crate) fn extend_epoch_lifecycle_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochLifecycle(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for an epoch's cycle-phase tracking (temporary storage)
+/// Should be called whenever the cycle phase is read/written
+pub(crate) fn extend_epoch_cycle_status_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochCycleStatus(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a pending bonded challenge (temporary storage)
+/// Should be called whenever a session's challenge is read/written
+pub(crate) fn extend_challenge_ttl(env: &Env, session_id: u32) {
     env.storage().temporary().extend_ttl(
-        &DataKey::Claimed(user.clone(), epoch),
+        &DataKey::Challenge(session_id),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+pub(crate) fn extend_dispute_ttl(env: &Env, session_id: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::Dispute(session_id),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's unbonding queue (persistent storage)
+/// Should be called whenever the unbonding queue is read/written
+pub(crate) fn extend_unlocking_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Unlocking(player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a session's recorded start time (temporary storage)
+/// Should be called whenever a session's start time is read/written
+pub(crate) fn extend_game_start_time_ttl(env: &Env, session_id: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::GameStartTime(session_id),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a session's expired-game marker (temporary storage)
+/// Should be called whenever a session is marked expired
+pub(crate) fn extend_expired_game_ttl(env: &Env, session_id: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::ExpiredGame(session_id),
         TTL_THRESHOLD_LEDGERS,
         TTL_EXTEND_TO_LEDGERS,
     );
@@ -300,6 +1410,76 @@ pub(crate) fn extend_session_ttl(env: &Env, session_id: u32) {
     );
 }
 
+/// Extend TTL for an N-player session's pot (temporary storage)
+/// Should be called whenever the pot is read/written
+pub(crate) fn extend_pot_ttl(env: &Env, session_id: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::Pot(session_id),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's settled reward (temporary storage)
+/// Should be called whenever a settled reward is read/written
+pub(crate) fn extend_settled_reward_ttl(env: &Env, epoch: u32, user: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::SettledReward(epoch, user.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for dust-settlement tracking data (temporary storage)
+/// Should be called whenever dust-settlement data is read/written
+pub(crate) fn extend_dust_settled_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::DustSettled(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for an epoch's frozen winning-faction FP total (temporary storage)
+/// Should be called whenever the frozen total is read/written
+pub(crate) fn extend_frozen_winning_fp_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::FrozenWinningFp(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's time-weighted FP accumulator (temporary storage)
+/// Should be called whenever it's read/written
+pub(crate) fn extend_player_weighted_fp_ttl(env: &Env, epoch: u32, player: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::PlayerWeightedFp(epoch, player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a faction's time-weighted total FP accumulator (temporary storage)
+/// Should be called whenever it's read/written
+pub(crate) fn extend_faction_weighted_fp_ttl(env: &Env, epoch: u32, faction: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::FactionWeightedFp(epoch, faction),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for an epoch's frozen winning-faction time-weighted FP total
+/// (temporary storage). Should be called whenever the frozen total is read/written
+pub(crate) fn extend_frozen_winning_weighted_fp_ttl(env: &Env, epoch: u32) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::FrozenWinningWeightedFp(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
 /// Extend TTL for instance storage (contract-wide data)
 /// Should be called during initialization and periodically
 pub(crate) fn extend_instance_ttl(env: &Env) {
@@ -126,7 +126,7 @@ fn test_number_guess_game_integration() {
     number_guess_client.make_guess(&game_id, &player2, &7);
 
     // Reveal winner - this also ends the game in Blendizzard
-    let winner = number_guess_client.reveal_winner(&game_id);
+    let winner = number_guess_client.settle(&game_id);
 
     // Verify FP accounting after game
     let winner_epoch = blendizzard.get_epoch_player(&winner);
@@ -200,12 +200,12 @@ fn test_multiple_number_guess_games() {
     // Game 1
     number_guess_client.make_guess(&game1, &player1, &5);
     number_guess_client.make_guess(&game1, &player2, &6);
-    number_guess_client.reveal_winner(&game1); // Ends in Blendizzard
+    number_guess_client.settle(&game1); // Ends in Blendizzard
 
     // Game 2
     number_guess_client.make_guess(&game2, &player3, &3);
     number_guess_client.make_guess(&game2, &player4, &8);
-    number_guess_client.reveal_winner(&game2); // Ends in Blendizzard
+    number_guess_client.settle(&game2); // Ends in Blendizzard
 
     // Verify faction standings reflect both games
     let epoch_info = blendizzard.get_epoch(&None);
@@ -304,7 +304,7 @@ fn test_loser_fp_is_deducted() {
     let game_id = number_guess_client.start_game(&session_id, &player1, &player2, &wager1, &wager2);
     number_guess_client.make_guess(&game_id, &player1, &5);
     number_guess_client.make_guess(&game_id, &player2, &7);
-    let winner = number_guess_client.reveal_winner(&game_id);
+    let winner = number_guess_client.settle(&game_id);
 
     // Get final FP after game
     let loser = if winner == player1 { player2.clone() } else { player1.clone() };
@@ -352,7 +352,7 @@ fn test_winner_fp_returned_loser_fp_spent() {
     // Play and reveal
     number_guess_client.make_guess(&game_id, &player1, &5);
     number_guess_client.make_guess(&game_id, &player2, &7);
-    let winner = number_guess_client.reveal_winner(&game_id);
+    let winner = number_guess_client.settle(&game_id);
 
     // Verify final state
     let winner_final = blendizzard.get_epoch_player(&winner);
@@ -407,7 +407,7 @@ fn test_asymmetric_wagers() {
 
     number_guess_client.make_guess(&game_id, &player1, &5);
     number_guess_client.make_guess(&game_id, &player2, &7);
-    let winner = number_guess_client.reveal_winner(&game_id);
+    let winner = number_guess_client.settle(&game_id);
 
     // Verify correct wager amounts contributed
     let winner_final = blendizzard.get_epoch_player(&winner);
@@ -502,7 +502,7 @@ fn test_cannot_reveal_before_both_guess() {
     number_guess_client.make_guess(&game_id, &player1, &5);
 
     // Try to reveal before player2 guesses - should panic
-    number_guess_client.reveal_winner(&game_id);
+    number_guess_client.settle(&game_id);
 }
 
 #[test]
@@ -544,7 +544,7 @@ fn test_tie_game_player1_wins() {
     number_guess_client.make_guess(&game_id, &player1, &5);
     number_guess_client.make_guess(&game_id, &player2, &5);
 
-    let winner = number_guess_client.reveal_winner(&game_id);
+    let winner = number_guess_client.settle(&game_id);
 
     // In a tie, player1 should always win (per contract logic: distance1 <= distance2)
     assert_eq!(winner, player1, "Player1 should win in tie games");
@@ -593,6 +593,237 @@ fn test_abandoned_game_fp_stays_locked() {
     // to handle abandoned games. For now, this demonstrates FP is correctly locked.
 }
 
+#[test]
+fn test_resolve_expired_game_refunds_locked_fp() {
+    use soroban_sdk::testutils::Ledger as _;
+
+    let env = setup_test_env();
+    let (
+        _admin,
+        number_guess_addr,
+        number_guess_client,
+        mock_vault,
+        blendizzard,
+    ) = setup_number_guess_test(&env);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    mock_vault.set_user_balance(&player1, &1000_0000000);
+    mock_vault.set_user_balance(&player2, &1000_0000000);
+
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    let game_timeout = 86_400; // 1 day
+    blendizzard.set_game_timeout(&game_timeout);
+
+    let session_id = 17u32;
+    let wager = 100_0000000;
+
+    number_guess_client.start_game(&session_id, &player1, &player2, &wager, &wager);
+
+    // Never call end_game - the session is abandoned. Warp past the timeout.
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp.checked_add(game_timeout + 1).unwrap();
+    });
+
+    blendizzard.resolve_expired_game(&number_guess_addr, &session_id);
+
+    // Wagers are refunded through the unbonding queue, so locked_fp drops
+    // immediately while the unbonded amount waits in the queue rather than
+    // going straight to available_fp.
+    let p1_epoch = blendizzard.get_epoch_player(&player1);
+    let p2_epoch = blendizzard.get_epoch_player(&player2);
+
+    assert_eq!(p1_epoch.locked_fp, 0, "Player1's wager should be unlocked");
+    assert_eq!(p2_epoch.locked_fp, 0, "Player2's wager should be unlocked");
+    assert_eq!(p1_epoch.total_fp_contributed, 0, "No contribution from an expired game");
+    assert_eq!(p2_epoch.total_fp_contributed, 0, "No contribution from an expired game");
+}
+
+#[test]
+fn test_start_game_multi_splits_pot_by_basis_points() {
+    let env = setup_test_env();
+    let (
+        _admin,
+        number_guess_addr,
+        _number_guess_client,
+        mock_vault,
+        blendizzard,
+    ) = setup_number_guess_test(&env);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let player3 = Address::generate(&env);
+
+    mock_vault.set_user_balance(&player1, &1000_0000000);
+    mock_vault.set_user_balance(&player2, &1000_0000000);
+    mock_vault.set_user_balance(&player3, &1000_0000000);
+
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+    blendizzard.select_faction(&player3, &1);
+
+    let session_id = 18u32;
+    let wager = 100_0000000;
+    let entrants = vec![
+        &env,
+        (player1.clone(), wager),
+        (player2.clone(), wager),
+        (player3.clone(), wager),
+    ];
+
+    blendizzard.start_game_multi(&number_guess_addr, &session_id, &entrants);
+
+    // Tournament finishes in a tie between player2 and player3 - a 50/50
+    // split that a single winner-take-all outcome couldn't express.
+    let payouts = vec![
+        &env,
+        (player2.clone(), 5_000u32),
+        (player3.clone(), 5_000u32),
+    ];
+    blendizzard.end_game_multi(&session_id, &payouts);
+
+    let total_pot = wager * 3;
+    let p1_epoch = blendizzard.get_epoch_player(&player1);
+    let p2_epoch = blendizzard.get_epoch_player(&player2);
+    let p3_epoch = blendizzard.get_epoch_player(&player3);
+
+    assert_eq!(p1_epoch.total_fp_contributed, 0, "Non-winner gets no contribution");
+    assert_eq!(p2_epoch.total_fp_contributed, total_pot / 2, "Tied winner gets half the pot");
+    assert_eq!(p3_epoch.total_fp_contributed, total_pot / 2, "Tied winner gets half the pot");
+}
+
+#[test]
+fn test_commission_skimmed_from_pot_before_faction_credit() {
+    let env = setup_test_env();
+    let (
+        _admin,
+        _number_guess_addr,
+        number_guess_client,
+        mock_vault,
+        blendizzard,
+    ) = setup_number_guess_test(&env);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    mock_vault.set_user_balance(&player1, &1000_0000000);
+    mock_vault.set_user_balance(&player2, &1000_0000000);
+
+    blendizzard.select_faction(&player1, &0);
+    blendizzard.select_faction(&player2, &1);
+
+    // 5% protocol commission
+    let commission_bps = 500u32;
+    blendizzard.set_commission(&commission_bps);
+    assert_eq!(blendizzard.get_commission(), commission_bps);
+
+    let session_id = 19u32;
+    let wager = 100_0000000;
+    let game_id = number_guess_client.start_game(&session_id, &player1, &player2, &wager, &wager);
+
+    number_guess_client.make_guess(&game_id, &player1, &5);
+    number_guess_client.make_guess(&game_id, &player2, &7);
+    let winner = number_guess_client.settle(&game_id);
+
+    let fee = wager * commission_bps as i128 / 10_000;
+    let distributable = wager - fee;
+
+    let winner_final = blendizzard.get_epoch_player(&winner);
+    assert_eq!(
+        winner_final.total_fp_contributed, distributable,
+        "Winner's faction contribution should reflect the pot net of commission"
+    );
+}
+
+#[test]
+fn test_faction_delegation_counts_toward_standings_without_playing() {
+    let env = setup_test_env();
+    let (_admin, _number_guess_addr, _number_guess_client, mock_vault, blendizzard) =
+        setup_number_guess_test(&env);
+
+    let backer = Address::generate(&env);
+    mock_vault.set_user_balance(&backer, &1000_0000000);
+    blendizzard.select_faction(&backer, &0);
+
+    let amount = 150_0000000;
+    blendizzard.delegate_fp_to_faction(&backer, &0, &amount);
+
+    let backer_epoch = blendizzard.get_epoch_player(&backer);
+    assert_eq!(
+        backer_epoch.total_fp_contributed, amount,
+        "Delegator should be credited as if they'd won a game for this amount"
+    );
+    assert_eq!(backer_epoch.locked_fp, amount, "Delegated FP should be locked");
+
+    let epoch_info = blendizzard.get_epoch(&None);
+    assert_eq!(
+        epoch_info.faction_standings.get(0).unwrap(),
+        amount,
+        "Faction standing should reflect the delegation immediately"
+    );
+}
+
+#[test]
+fn test_undelegate_fp_from_faction_reverses_contribution() {
+    let env = setup_test_env();
+    let (_admin, _number_guess_addr, _number_guess_client, mock_vault, blendizzard) =
+        setup_number_guess_test(&env);
+
+    let backer = Address::generate(&env);
+    mock_vault.set_user_balance(&backer, &1000_0000000);
+    blendizzard.select_faction(&backer, &0);
+
+    let amount = 150_0000000;
+    blendizzard.delegate_fp_to_faction(&backer, &0, &amount);
+    blendizzard.undelegate_fp_from_faction(&backer);
+
+    let backer_epoch = blendizzard.get_epoch_player(&backer);
+    assert_eq!(
+        backer_epoch.total_fp_contributed, 0,
+        "Undelegating should reverse the contribution"
+    );
+    assert_eq!(backer_epoch.locked_fp, 0, "Undelegating should release the locked FP");
+
+    let epoch_info = blendizzard.get_epoch(&None);
+    assert_eq!(
+        epoch_info.faction_standings.get(0).unwrap_or(0),
+        0,
+        "Undelegating should reverse the faction standing"
+    );
+}
+
+#[test]
+fn test_force_undelegate_bypasses_frozen_epoch() {
+    let env = setup_test_env();
+    let (_admin, _number_guess_addr, _number_guess_client, mock_vault, blendizzard) =
+        setup_number_guess_test(&env);
+
+    let backer = Address::generate(&env);
+    mock_vault.set_user_balance(&backer, &1000_0000000);
+    blendizzard.select_faction(&backer, &0);
+
+    let amount = 150_0000000;
+    blendizzard.delegate_fp_to_faction(&backer, &0, &amount);
+
+    blendizzard.freeze_epoch();
+
+    // Normal withdrawal is blocked once the epoch is frozen...
+    let result = blendizzard.try_undelegate_fp_from_faction(&backer);
+    assert!(result.is_err(), "Regular undelegate should be blocked once the epoch is frozen");
+
+    // ...but the admin's emergency path isn't.
+    blendizzard.force_undelegate(&backer);
+
+    let backer_epoch = blendizzard.get_epoch_player(&backer);
+    assert_eq!(
+        backer_epoch.total_fp_contributed, 0,
+        "force_undelegate should reverse the contribution regardless of epoch state"
+    );
+}
+
 // ============================================================================
 // Full Epoch Cycle and Rewards Test
 // ============================================================================
@@ -700,21 +931,21 @@ fn test_full_epoch_cycle_with_rewards() {
     let game1 = number_guess_client.start_game(&session1, &player1, &player2, &wager, &wager);
     number_guess_client.make_guess(&game1, &player1, &5);
     number_guess_client.make_guess(&game1, &player2, &7);
-    let winner1 = number_guess_client.reveal_winner(&game1);
+    let winner1 = number_guess_client.settle(&game1);
 
     // Game 2: player3 vs player4
     let session2 = 21u32;
     let game2 = number_guess_client.start_game(&session2, &player3, &player4, &wager, &wager);
     number_guess_client.make_guess(&game2, &player3, &3);
     number_guess_client.make_guess(&game2, &player4, &8);
-    let winner2 = number_guess_client.reveal_winner(&game2);
+    let winner2 = number_guess_client.settle(&game2);
 
     // Game 3: player1 vs player4 (another game for more FP contribution)
     let session3 = 22u32;
     let game3 = number_guess_client.start_game(&session3, &player1, &player4, &wager, &wager);
     number_guess_client.make_guess(&game3, &player1, &6);
     number_guess_client.make_guess(&game3, &player4, &4);
-    let winner3 = number_guess_client.reveal_winner(&game3);
+    let winner3 = number_guess_client.settle(&game3);
 
     // ========================================================================
     // Step 4: Verify faction standings after games
@@ -737,18 +968,28 @@ fn test_full_epoch_cycle_with_rewards() {
         li.timestamp = li.timestamp.checked_add(epoch_duration + 1).unwrap();
     });
 
-    // Cycle epoch - this will:
-    // 1. Finalize epoch 0
-    // 2. Determine winning faction
-    // 3. Swap BLND â†’ USDC
-    // 4. Set reward pool
-    // 5. Start epoch 1
-    let result = blendizzard.try_cycle_epoch();
-
-    // Handle potential swap failures gracefully
-    if result.is_err() {
-        // Epoch cycling can fail if there's insufficient BLND
-        // For this test, we'll accept this and skip reward verification
+    // Cycle epoch - each call advances one bounded phase (Active -> Finalizing
+    // -> Swapping -> Distributing -> next epoch's Active), so drive it to
+    // completion with up to one call per phase:
+    // 1. Finalize epoch 0, determine winning faction
+    // 2. Swap BLND -> USDC, fund the reward pool
+    // 3. Confirm the pool is ready for claims
+    // 4. Start epoch 1
+    let mut cycled = false;
+    for _ in 0..4 {
+        let result = blendizzard.try_cycle_epoch();
+        if result.is_err() {
+            // Epoch cycling can fail if there's insufficient BLND
+            // For this test, we'll accept this and skip reward verification
+            return;
+        }
+        if blendizzard.get_epoch(&None).epoch_number != 0 {
+            cycled = true;
+            break;
+        }
+    }
+    if !cycled {
+        // Epoch 0 never advanced within the expected number of phases
         return;
     }
 
@@ -798,16 +1039,17 @@ fn test_full_epoch_cycle_with_rewards() {
                 // They should be able to claim rewards
                 let usdc_before = usdc_token_client.balance(&player);
 
-                let claimed_amount = blendizzard.claim_epoch_reward(&player, &0);
+                let (claimed_amount, shares_minted) = blendizzard.claim_epoch_reward(&player, &0);
 
                 let usdc_after = usdc_token_client.balance(&player);
 
-                // Verify USDC was transferred
+                // The claim is deposited straight into the vault on the
+                // player's behalf, so their own wallet balance doesn't move.
                 assert!(claimed_amount > 0, "Winner should receive USDC rewards");
+                assert!(shares_minted > 0, "Claim should mint vault shares");
                 assert_eq!(
-                    usdc_after,
-                    usdc_before + claimed_amount,
-                    "USDC balance should increase by claimed amount"
+                    usdc_after, usdc_before,
+                    "USDC balance should be untouched - the claim is vaulted, not transferred"
                 );
 
                 // Verify claim is recorded
@@ -870,7 +1112,7 @@ fn test_full_epoch_cycle_with_rewards() {
     let game4 = number_guess_client.start_game(&session4, &player1, &player2, &wager, &wager);
     number_guess_client.make_guess(&game4, &player1, &5);
     number_guess_client.make_guess(&game4, &player2, &6);
-    let _winner4 = number_guess_client.reveal_winner(&game4);
+    let _winner4 = number_guess_client.settle(&game4);
 
     // Verify epoch 1 standings are being tracked
     let epoch1_after_game = blendizzard.get_epoch(&None);
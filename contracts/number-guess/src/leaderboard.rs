@@ -0,0 +1,190 @@
+//! Cross-game leaderboard: per-address stats accumulated across every game
+//! this contract settles, plus a bounded top-K index so `top_players` never
+//! has to scan every player that's ever played.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// How many ranked entries the top-players index keeps. Bounded so
+/// `record_result` never has to re-sort an unbounded player set.
+const TOP_K: u32 = 100;
+
+/// Rank positions are grouped into buckets of this size; `leaderboard_updated`
+/// only fires when a player crosses a bucket boundary, not on every single
+/// rank change.
+const RANK_BUCKET_SIZE: u32 = 10;
+
+const TTL_THRESHOLD_LEDGERS: u32 = 120_960; // ~7 days
+const TTL_EXTEND_TO_LEDGERS: u32 = 518_400; // ~30 days
+
+/// A player's accumulated record across every game this contract has settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    /// Cumulative wager amount the player has put up across every game
+    /// (gross, not profit-and-loss net).
+    pub net_wagered: i128,
+    /// Consecutive wins as of the player's most recent game; resets to 0 on
+    /// a loss.
+    pub win_streak: u32,
+}
+
+/// One row of the top-players index, ordered by `wins` descending with
+/// `net_wagered` as a tiebreaker.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub wins: u32,
+    pub net_wagered: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum DataKey {
+    Stats(Address),
+    TopPlayers,
+}
+
+/// Fold a settled game's outcome for one player into their cross-game stats,
+/// then update the top-players index and emit `leaderboard_updated` if the
+/// player's rank bucket changed.
+pub(crate) fn record_result(env: &Env, player: &Address, wager: i128, won: bool) {
+    let mut stats = get_stats(env, player).unwrap_or(PlayerStats {
+        games_played: 0,
+        wins: 0,
+        losses: 0,
+        net_wagered: 0,
+        win_streak: 0,
+    });
+    stats.games_played += 1;
+    stats.net_wagered += wager;
+    if won {
+        stats.wins += 1;
+        stats.win_streak += 1;
+    } else {
+        stats.losses += 1;
+        stats.win_streak = 0;
+    }
+    set_stats(env, player, &stats);
+    update_top_players(env, player, &stats);
+}
+
+/// Look up a player's accumulated stats. Players who've never finished a
+/// game get the all-zero default rather than a missing-value error.
+pub(crate) fn get_player_stats(env: &Env, player: &Address) -> PlayerStats {
+    get_stats(env, player).unwrap_or(PlayerStats {
+        games_played: 0,
+        wins: 0,
+        losses: 0,
+        net_wagered: 0,
+        win_streak: 0,
+    })
+}
+
+/// A page of the top-players index, ordered by rank. `offset`/`limit` beyond
+/// the index's length just return fewer (or zero) entries.
+pub(crate) fn top_players(env: &Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+    let top = get_top_players(env);
+    let mut page: Vec<LeaderboardEntry> = Vec::new(env);
+    let end = (offset + limit).min(top.len());
+    let mut i = offset;
+    while i < end {
+        page.push_back(top.get(i).unwrap());
+        i += 1;
+    }
+    page
+}
+
+fn get_stats(env: &Env, player: &Address) -> Option<PlayerStats> {
+    let key = DataKey::Stats(player.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_stats_ttl(env, player);
+    }
+    result
+}
+
+fn set_stats(env: &Env, player: &Address, stats: &PlayerStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stats(player.clone()), stats);
+    extend_stats_ttl(env, player);
+}
+
+fn extend_stats_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Stats(player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn get_top_players(env: &Env) -> Vec<LeaderboardEntry> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TopPlayers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Re-rank the bounded top-K index for one player's updated stats, evicting
+/// the lowest-ranked entry if the index grows past `TOP_K`. Scanning/rebuilding
+/// `TOP_K` entries on every settlement is cheap; ranking every player that's
+/// ever played would not be.
+fn update_top_players(env: &Env, player: &Address, stats: &PlayerStats) {
+    let mut top = get_top_players(env);
+
+    let old_rank = rank_of(&top, player);
+
+    let mut without_player: Vec<LeaderboardEntry> = Vec::new(env);
+    for i in 0..top.len() {
+        let entry = top.get(i).unwrap();
+        if &entry.player != player {
+            without_player.push_back(entry);
+        }
+    }
+    top = without_player;
+
+    let entry = LeaderboardEntry {
+        player: player.clone(),
+        wins: stats.wins,
+        net_wagered: stats.net_wagered,
+    };
+    let mut insert_at = top.len();
+    for i in 0..top.len() {
+        let existing = top.get(i).unwrap();
+        if entry.wins > existing.wins
+            || (entry.wins == existing.wins && entry.net_wagered > existing.net_wagered)
+        {
+            insert_at = i;
+            break;
+        }
+    }
+    top.insert(insert_at, entry);
+    if top.len() > TOP_K {
+        top.pop_back();
+    }
+
+    let new_rank = rank_of(&top, player);
+    env.storage().instance().set(&DataKey::TopPlayers, &top);
+
+    let old_bucket = old_rank.map(|r| r / RANK_BUCKET_SIZE);
+    let new_bucket = new_rank.map(|r| r / RANK_BUCKET_SIZE);
+    if old_bucket != new_bucket {
+        env.events().publish(
+            (Symbol::new(env, "leaderboard_updated"), player.clone()),
+            new_rank,
+        );
+    }
+}
+
+fn rank_of(top: &Vec<LeaderboardEntry>, player: &Address) -> Option<u32> {
+    for i in 0..top.len() {
+        if &top.get(i).unwrap().player == player {
+            return Some(i);
+        }
+    }
+    None
+}
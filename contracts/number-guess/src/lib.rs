@@ -0,0 +1,661 @@
+#![no_std]
+
+//! Number Guess - a number-guessing wagering game built on Blendizzard.
+//!
+//! Players each lock a faction-point wager through Blendizzard, then guess a
+//! number between 1 and 10. Finishers are ranked by absolute distance from a
+//! sealed random number (ties favor the lower player index) and paid out per
+//! a basis-point payout table. The winning number is committed to at
+//! `start_game`/`start_game_multi` and only revealed once every player has
+//! guessed, so neither a player nor anyone watching the ledger can read it
+//! early.
+
+mod leaderboard;
+mod lobby;
+mod test;
+
+use leaderboard::{LeaderboardEntry, PlayerStats};
+use lobby::Challenge;
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, vec, Address, Bytes,
+    BytesN, Env, Vec,
+};
+
+/// Denominator for payout-table basis points: a table entry of `6_000` means
+/// 60% of the pot (`6_000 * total_pot / DENOM`).
+pub const DENOM: i128 = 10_000;
+
+/// Default guess window (ledger seconds) if `set_guess_window` is never
+/// called: how long players have to call `make_guess` before `claim_timeout`
+/// can force a resolution.
+pub const DEFAULT_GUESS_WINDOW_SECONDS: u64 = 3_600;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    NotPlayer = 2,
+    AlreadyGuessed = 3,
+    BothPlayersNotGuessed = 4,
+    InvalidCommitment = 5,
+    InvalidPayoutTable = 6,
+    DeadlineNotReached = 7,
+    GameStillActive = 8,
+    ChallengeNotFound = 9,
+    ChallengeAlreadyMatched = 10,
+    NotChallengeCreator = 11,
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Active,
+    Ended,
+}
+
+/// A game's ranked payout table: `payout_table[i]` is the basis-point share
+/// of the total pot paid to the player ranked `i` by closeness of guess
+/// (rank 0 = closest). Shares beyond the table's length are zero, and the
+/// table must sum to exactly `DENOM`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub payout_table: Vec<i128>,
+}
+
+/// Public game state. `commitment` is the sealed winning number; `winning_number`
+/// stays `None` until `settle` opens the commitment.
+///
+/// A head-to-head `start_game` session is just the `players.len() == 2` case
+/// with a winner-take-all `GameConfig` (`payout_table: [DENOM]`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub session_id: u32,
+    pub players: Vec<Address>,
+    pub wagers: Vec<i128>,
+    pub guesses: Vec<Option<u32>>,
+    pub config: GameConfig,
+    pub commitment: BytesN<32>,
+    pub winning_number: Option<u32>,
+    pub status: GameStatus,
+    /// The rank-0 (closest-guess) finisher, set once `settle` runs.
+    pub winner: Option<Address>,
+    /// Ledger timestamp after which `claim_timeout` may force a resolution
+    /// if not every player has guessed yet.
+    pub guess_deadline: u64,
+}
+
+/// The sealed `(number, nonce)` pair backing a game's commitment. Kept out of
+/// `Game` so `get_game` can never leak the number before settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Secret {
+    number: u32,
+    nonce: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum DataKey {
+    Admin,
+    Blendizzard,
+    GameCounter,
+    GuessWindow,
+    Game(u32),
+    Secret(u32),
+}
+
+/// Minimal view of Blendizzard's public interface that number-guess depends on.
+/// Kept local to avoid a crate dependency on `blendizzard` itself. Mirrors
+/// Blendizzard's real interface exactly. Two-player sessions are settled
+/// through the pairwise `start_game`/`end_game` (a binary `player1_won` call -
+/// Blendizzard has no "void" outcome there either; an abandoned session is
+/// refunded through the separate, permissionless `resolve_expired_game`
+/// instead), while true N-player tournaments use the native pot primitives,
+/// `start_game_multi`/`end_game_multi`, which can award an arbitrary
+/// basis-point split across every entrant in one call.
+#[contractclient(name = "BlendizzardClient")]
+pub trait BlendizzardInterface {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_wager: i128,
+        player2_wager: i128,
+    );
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn resolve_expired_game(env: Env, game: Address, session_id: u32);
+    fn start_game_multi(env: Env, game_id: Address, session_id: u32, entrants: Vec<(Address, i128)>);
+    fn end_game_multi(env: Env, session_id: u32, payouts: Vec<(Address, u32)>);
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct NumberGuessContract;
+
+#[contractimpl]
+impl NumberGuessContract {
+    pub fn __constructor(env: Env, admin: Address, blendizzard: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::Blendizzard, &blendizzard);
+        env.storage().instance().set(&DataKey::GameCounter, &0u32);
+        env.storage()
+            .instance()
+            .set(&DataKey::GuessWindow, &DEFAULT_GUESS_WINDOW_SECONDS);
+    }
+
+    /// Configure how long (in ledger seconds) players have to guess before
+    /// `claim_timeout` can force a resolution. Admin-only; applies to games
+    /// started after the call.
+    pub fn set_guess_window(env: Env, window_seconds: u64) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::GuessWindow, &window_seconds);
+    }
+
+    /// Start a head-to-head session: a `start_game_multi` with exactly two
+    /// players and a winner-take-all payout table.
+    ///
+    /// # Errors
+    /// Bubbles up whatever Blendizzard's `start_game` rejects with (e.g. the
+    /// game isn't whitelisted, a player hasn't selected a faction).
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_wager: i128,
+        player2_wager: i128,
+    ) -> u32 {
+        Self::start_game_multi(
+            env.clone(),
+            session_id,
+            vec![&env, player1, player2],
+            vec![&env, player1_wager, player2_wager],
+            vec![&env, DENOM],
+        )
+        .expect("two-player payout table is always valid")
+    }
+
+    /// Start an N-player tournament session sharing a sealed winning number.
+    ///
+    /// `payout_table[i]` is the basis-point share of the total pot paid to the
+    /// player ranked `i` by closeness of guess; shares beyond the table's
+    /// length are zero. The table must sum to exactly `DENOM`.
+    ///
+    /// Exactly two players lock their wagers through Blendizzard's pairwise
+    /// `start_game`, the same session kind the dispute-window and
+    /// `resolve_expired_game` timeout-refund paths understand. Three or more
+    /// players lock through Blendizzard's native N-player `start_game_multi`
+    /// pot instead, which `settle` later pays out in full via
+    /// `end_game_multi`.
+    ///
+    /// # Errors
+    /// * `InvalidPayoutTable` - If `players`/`wagers` lengths mismatch, or
+    ///   their shares don't sum to `DENOM`
+    pub fn start_game_multi(
+        env: Env,
+        session_id: u32,
+        players: Vec<Address>,
+        wagers: Vec<i128>,
+        payout_table: Vec<i128>,
+    ) -> Result<u32, Error> {
+        if players.len() != wagers.len() || players.is_empty() {
+            return Err(Error::InvalidPayoutTable);
+        }
+        let mut total_shares: i128 = 0;
+        for share in payout_table.iter() {
+            if !(0..=DENOM).contains(&share) {
+                return Err(Error::InvalidPayoutTable);
+            }
+            total_shares += share;
+        }
+        if total_shares != DENOM {
+            return Err(Error::InvalidPayoutTable);
+        }
+        let config = GameConfig { payout_table };
+
+        let blendizzard: Address = env.storage().instance().get(&DataKey::Blendizzard).unwrap();
+        let blendizzard_client = BlendizzardClient::new(&env, &blendizzard);
+        if players.len() == 2 {
+            blendizzard_client.start_game(
+                &env.current_contract_address(),
+                &session_id,
+                &players.get(0).unwrap(),
+                &players.get(1).unwrap(),
+                &wagers.get(0).unwrap(),
+                &wagers.get(1).unwrap(),
+            );
+        } else {
+            let mut entrants: Vec<(Address, i128)> = Vec::new(&env);
+            for i in 0..players.len() {
+                entrants.push_back((players.get(i).unwrap(), wagers.get(i).unwrap()));
+            }
+            blendizzard_client.start_game_multi(&env.current_contract_address(), &session_id, &entrants);
+        }
+
+        let game_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey::GameCounter, &game_id);
+
+        let number = env.prng().gen_range(1u32..=10);
+        let nonce: BytesN<32> = env.prng().gen();
+        let commitment = commit(&env, number, &nonce);
+
+        let mut guesses: Vec<Option<u32>> = Vec::new(&env);
+        for _ in 0..players.len() {
+            guesses.push_back(None);
+        }
+
+        let guess_window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::GuessWindow)
+            .unwrap_or(DEFAULT_GUESS_WINDOW_SECONDS);
+        let guess_deadline = env.ledger().timestamp() + guess_window;
+
+        let game = Game {
+            session_id,
+            players,
+            wagers,
+            guesses,
+            config,
+            commitment,
+            winning_number: None,
+            status: GameStatus::Active,
+            winner: None,
+            guess_deadline,
+        };
+        env.storage()
+            .temporary()
+            .set(&DataKey::Game(game_id), &game);
+        env.storage()
+            .temporary()
+            .set(&DataKey::Secret(game_id), &Secret { number, nonce });
+
+        Ok(game_id)
+    }
+
+    /// Open a challenge for a future head-to-head `start_game`: only the
+    /// creator's wager is known up front, so no counterparty needs to be
+    /// agreed on ahead of time. Nothing is escrowed with Blendizzard yet.
+    pub fn open_challenge(env: Env, session_id: u32, creator: Address, wager: i128) -> u32 {
+        creator.require_auth();
+        lobby::open_challenge(&env, session_id, &creator, wager)
+    }
+
+    /// Match a second player into an open challenge, atomically promoting it
+    /// into a live head-to-head `Game` (winner-take-all, via
+    /// `start_game_multi`).
+    ///
+    /// # Errors
+    /// * `ChallengeNotFound` - If `challenge_id` doesn't exist
+    /// * `ChallengeAlreadyMatched` - If the challenge was already joined or cancelled
+    pub fn join_challenge(
+        env: Env,
+        challenge_id: u32,
+        joiner: Address,
+        wager: i128,
+    ) -> Result<u32, Error> {
+        joiner.require_auth();
+        let challenge = lobby::take_open_challenge(&env, challenge_id)?;
+        lobby::mark_matched(&env, &challenge);
+
+        Self::start_game_multi(
+            env.clone(),
+            challenge.session_id,
+            vec![&env, challenge.creator, joiner],
+            vec![&env, challenge.wager, wager],
+            vec![&env, DENOM],
+        )
+    }
+
+    /// Reclaim a challenge's wager before anyone joins. Creator-only.
+    ///
+    /// # Errors
+    /// * `ChallengeNotFound` - If `challenge_id` doesn't exist
+    /// * `ChallengeAlreadyMatched` - If the challenge was already joined or cancelled
+    /// * `NotChallengeCreator` - If `caller` didn't create the challenge
+    pub fn cancel_challenge(env: Env, challenge_id: u32, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        lobby::cancel_challenge(&env, challenge_id, &caller)
+    }
+
+    /// A page of still-open (unmatched, uncancelled) challenges, for clients
+    /// to browse and join.
+    pub fn list_open_challenges(env: Env, offset: u32, limit: u32) -> Vec<Challenge> {
+        lobby::list_open_challenges(&env, offset, limit)
+    }
+
+    /// Fetch a game's public state. `winning_number` is `None` until settlement.
+    pub fn get_game(env: Env, game_id: u32) -> Game {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Game(game_id))
+            .expect("Game not found")
+    }
+
+    /// Record a player's guess. Each player may guess exactly once.
+    ///
+    /// # Errors
+    /// * `GameNotFound` - If `game_id` doesn't exist
+    /// * `NotPlayer` - If `player` isn't a participant in the session
+    /// * `AlreadyGuessed` - If `player` already submitted a guess
+    pub fn make_guess(env: Env, game_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        if !(1..=10).contains(&guess) {
+            panic!("Guess must be between 1 and 10");
+        }
+
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(game_id))
+            .ok_or(Error::GameNotFound)?;
+
+        let index = game
+            .players
+            .iter()
+            .position(|p| p == player)
+            .ok_or(Error::NotPlayer)?;
+
+        if game.guesses.get(index as u32).unwrap().is_some() {
+            return Err(Error::AlreadyGuessed);
+        }
+        game.guesses.set(index as u32, Some(guess));
+
+        env.storage()
+            .temporary()
+            .set(&DataKey::Game(game_id), &game);
+        Ok(())
+    }
+
+    /// Open the commitment, rank every player by closeness of guess, and pay
+    /// out the ranked payout table in a single Blendizzard settlement call -
+    /// the pairwise `end_game` for a two-player game, or `end_game_multi`
+    /// with every rank's basis-point share for a true N-player tournament.
+    /// Idempotent: settling an already-ended game just returns the recorded
+    /// rank-0 winner again without re-settling.
+    ///
+    /// # Errors
+    /// * `GameNotFound` - If `game_id` doesn't exist
+    /// * `BothPlayersNotGuessed` - If any player hasn't guessed yet
+    /// * `InvalidCommitment` - If the stored secret doesn't hash to the commitment
+    pub fn settle(env: Env, game_id: u32) -> Result<Address, Error> {
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(game_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if let Some(winner) = game.winner.clone() {
+            return Ok(winner);
+        }
+
+        if game.guesses.iter().any(|g| g.is_none()) {
+            return Err(Error::BothPlayersNotGuessed);
+        }
+
+        let secret: Secret = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Secret(game_id))
+            .ok_or(Error::GameNotFound)?;
+        if commit(&env, secret.number, &secret.nonce) != game.commitment {
+            return Err(Error::InvalidCommitment);
+        }
+        let winning_number = secret.number;
+
+        // Rank every player by absolute distance from the winning number;
+        // ties favor the lower player index (matches the original
+        // head-to-head rule).
+        let mut all_players: Vec<u32> = Vec::new(&env);
+        for i in 0..game.players.len() {
+            all_players.push_back(i);
+        }
+        let ranking = rank_by_closeness(&game, all_players, winning_number);
+        let len = ranking.len();
+
+        let winner = game.players.get(ranking.get(0).unwrap()).unwrap();
+
+        game.winning_number = Some(winning_number);
+        game.winner = Some(winner.clone());
+        game.status = GameStatus::Ended;
+        env.storage()
+            .temporary()
+            .set(&DataKey::Game(game_id), &game);
+
+        let blendizzard: Address = env.storage().instance().get(&DataKey::Blendizzard).unwrap();
+        let blendizzard_client = BlendizzardClient::new(&env, &blendizzard);
+        if game.players.len() == 2 {
+            let player1_won = winner == game.players.get(0).unwrap();
+            blendizzard_client.end_game(&game.session_id, &player1_won);
+        } else {
+            // A true N-player tournament: settle the whole pot in one call,
+            // splitting it by basis-point share exactly like `payout_table`
+            // specifies - unlike the two-player path, Blendizzard's pot
+            // primitive actually carries the payout amounts, so every
+            // recipient's real share is credited rather than just bookkept.
+            let mut payouts: Vec<(Address, u32)> = Vec::new(&env);
+            for rank in 0..len {
+                let share_bps = game.config.payout_table.get(rank).unwrap_or(0);
+                let payee = game.players.get(ranking.get(rank).unwrap()).unwrap();
+                payouts.push_back((payee, share_bps as u32));
+            }
+            blendizzard_client.end_game_multi(&game.session_id, &payouts);
+        }
+
+        for i in 0..game.players.len() {
+            let player = game.players.get(i).unwrap();
+            let wager = game.wagers.get(i).unwrap();
+            leaderboard::record_result(&env, &player, wager, player == winner);
+        }
+
+        Ok(winner)
+    }
+
+    /// Force-resolve a game that's stalled past its `guess_deadline`.
+    ///
+    /// Whoever guessed in time is ranked and paid out exactly like a normal
+    /// `settle` - the no-shows' forfeited payout-table shares are folded into
+    /// the lowest-ranked guesser rather than left unclaimed. Only when
+    /// nobody guessed does the game void, telling Blendizzard to refund
+    /// wagers instead of awarding them. Either way the game is marked
+    /// `Ended` so `settle` can no longer run.
+    ///
+    /// # Errors
+    /// * `GameNotFound` - If `game_id` doesn't exist
+    /// * `DeadlineNotReached` - If `now <= guess_deadline`
+    /// * `GameStillActive` - If every player has already guessed - the game
+    ///   hasn't stalled, `settle` is the right entrypoint
+    /// * `InvalidCommitment` - If the stored secret doesn't match the
+    ///   winning number's commitment (defense in depth; this contract is the
+    ///   only writer of the secret)
+    pub fn claim_timeout(env: Env, game_id: u32) -> Result<Address, Error> {
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(game_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if let Some(winner) = game.winner.clone() {
+            return Ok(winner);
+        }
+
+        if env.ledger().timestamp() <= game.guess_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let mut guessers: Vec<u32> = Vec::new(&env);
+        for i in 0..game.guesses.len() {
+            if game.guesses.get(i).unwrap().is_some() {
+                guessers.push_back(i);
+            }
+        }
+
+        if guessers.len() == game.players.len() {
+            return Err(Error::GameStillActive);
+        }
+
+        let blendizzard: Address = env.storage().instance().get(&DataKey::Blendizzard).unwrap();
+        let blendizzard_client = BlendizzardClient::new(&env, &blendizzard);
+
+        if guessers.is_empty() {
+            // Nobody guessed - there's no ranking to settle, so this is a
+            // true void. Blendizzard has no "void" outcome on `end_game` -
+            // refunding an unresolved session is a separate, permissionless
+            // primitive (`resolve_expired_game`), so that's what's used here
+            // instead of a fabricated Voided outcome. Nobody guessed, so
+            // there's nobody to credit on the leaderboard either.
+            blendizzard_client.resolve_expired_game(&env.current_contract_address(), &game.session_id);
+
+            // No winner in a void; return the (non-)player who was present,
+            // if any, for caller convenience.
+            let winner = game.players.get(0).unwrap();
+            game.winner = Some(winner.clone());
+            game.status = GameStatus::Ended;
+            env.storage()
+                .temporary()
+                .set(&DataKey::Game(game_id), &game);
+            return Ok(winner);
+        }
+
+        let winner = if game.players.len() == 2 {
+            // Exactly one of the two players guessed (the only way to reach
+            // here without both having guessed) - winner-take-all through
+            // the pairwise session, same as settle()'s two-player path.
+            let winner_index = guessers.get(0).unwrap();
+            let winner = game.players.get(winner_index).unwrap();
+            let player1_won = winner == game.players.get(0).unwrap();
+            blendizzard_client.end_game(&game.session_id, &player1_won);
+            winner
+        } else {
+            let secret: Secret = env
+                .storage()
+                .temporary()
+                .get(&DataKey::Secret(game_id))
+                .ok_or(Error::GameNotFound)?;
+            if commit(&env, secret.number, &secret.nonce) != game.commitment {
+                return Err(Error::InvalidCommitment);
+            }
+            let ranking = rank_by_closeness(&game, guessers.clone(), secret.number);
+
+            // Pay the whole pot out among whoever guessed, ranked by
+            // closeness. Every no-show's payout-table bucket is forfeited
+            // into the lowest-ranked guesser instead of going unclaimed, so
+            // `end_game_multi`'s shares still sum to exactly `DENOM`.
+            let mut payouts: Vec<(Address, u32)> = Vec::new(&env);
+            let mut allocated_bps: i128 = 0;
+            for rank in 0..ranking.len() {
+                let share_bps = game.config.payout_table.get(rank).unwrap_or(0);
+                allocated_bps += share_bps;
+                let payee = game.players.get(ranking.get(rank).unwrap()).unwrap();
+                payouts.push_back((payee, share_bps as u32));
+            }
+            let last = payouts.len() - 1;
+            let (last_payee, last_bps) = payouts.get(last).unwrap();
+            payouts.set(last, (last_payee, last_bps + (DENOM - allocated_bps) as u32));
+
+            blendizzard_client.end_game_multi(&game.session_id, &payouts);
+
+            game.players.get(ranking.get(0).unwrap()).unwrap()
+        };
+
+        for i in 0..game.players.len() {
+            let player = game.players.get(i).unwrap();
+            let wager = game.wagers.get(i).unwrap();
+            leaderboard::record_result(&env, &player, wager, player == winner);
+        }
+
+        game.winner = Some(winner.clone());
+        game.status = GameStatus::Ended;
+        env.storage()
+            .temporary()
+            .set(&DataKey::Game(game_id), &game);
+
+        Ok(winner)
+    }
+
+    /// A player's accumulated record across every game this contract has
+    /// settled. Players who've never finished a game get the all-zero
+    /// default.
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        leaderboard::get_player_stats(&env, &player)
+    }
+
+    /// A page of the cross-game leaderboard, ranked by wins (net wagered as
+    /// a tiebreaker). Backed by a bounded top-100 index rather than a scan
+    /// of every player that's ever played.
+    pub fn top_players(env: Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        leaderboard::top_players(&env, offset, limit)
+    }
+
+    /// Upgrade the contract's WASM. Admin-only.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+/// `sha256(number_byte || nonce)`, the commit-reveal binding between a sealed
+/// winning number and its public commitment.
+fn commit(env: &Env, number: u32, nonce: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::new(env);
+    preimage.push_back(number as u8);
+    preimage.append(&nonce.clone().into());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Rank `indices` (player indices into `game.guesses`) by absolute distance
+/// of their guess from `winning_number`, closest first; ties favor whichever
+/// index sorts first going in. Simple insertion sort: game sizes are small
+/// (tournament-scale), and soroban_sdk::Vec has no built-in sort_by.
+fn rank_by_closeness(game: &Game, indices: Vec<u32>, winning_number: u32) -> Vec<u32> {
+    let mut ranking = indices;
+    let distance = |i: u32| -> i32 {
+        let guess = game.guesses.get(i).unwrap().unwrap();
+        (guess as i32 - winning_number as i32).abs()
+    };
+    let len = ranking.len();
+    for i in 1..len {
+        let key = ranking.get(i).unwrap();
+        let key_dist = distance(key);
+        let mut j = i;
+        while j > 0 {
+            let prev = ranking.get(j - 1).unwrap();
+            if distance(prev) <= key_dist {
+                break;
+            }
+            ranking.set(j, prev);
+            j -= 1;
+        }
+        ranking.set(j, key);
+    }
+    ranking
+}
@@ -0,0 +1,158 @@
+//! Lobby / open-challenge matchmaking: park one player's wager until a
+//! second player joins, so a head-to-head session no longer needs a
+//! pre-agreed counterparty before `start_game` can run.
+
+use crate::Error;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChallengeStatus {
+    Open,
+    Matched,
+    Cancelled,
+}
+
+/// A pending head-to-head challenge. Nothing is escrowed with Blendizzard
+/// yet - FP-locking only happens once `join_challenge` knows both players'
+/// wagers and promotes this into a real `Game`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub id: u32,
+    pub session_id: u32,
+    pub creator: Address,
+    pub wager: i128,
+    pub status: ChallengeStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum DataKey {
+    ChallengeCounter,
+    Challenge(u32),
+    OpenChallenges,
+}
+
+/// Open a new challenge and add it to the browsable open-challenge index.
+pub(crate) fn open_challenge(env: &Env, session_id: u32, creator: &Address, wager: i128) -> u32 {
+    let challenge_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ChallengeCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::ChallengeCounter, &challenge_id);
+
+    let challenge = Challenge {
+        id: challenge_id,
+        session_id,
+        creator: creator.clone(),
+        wager,
+        status: ChallengeStatus::Open,
+    };
+    env.storage()
+        .temporary()
+        .set(&DataKey::Challenge(challenge_id), &challenge);
+
+    let mut open_ids = get_open_challenge_ids(env);
+    open_ids.push_back(challenge_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::OpenChallenges, &open_ids);
+
+    challenge_id
+}
+
+/// Fetch a challenge and confirm it's still open, ready for `join_challenge`
+/// to promote into a `Game`. Doesn't itself mark it matched - see
+/// `mark_matched`.
+pub(crate) fn take_open_challenge(env: &Env, challenge_id: u32) -> Result<Challenge, Error> {
+    let challenge: Challenge = env
+        .storage()
+        .temporary()
+        .get(&DataKey::Challenge(challenge_id))
+        .ok_or(Error::ChallengeNotFound)?;
+    if challenge.status != ChallengeStatus::Open {
+        return Err(Error::ChallengeAlreadyMatched);
+    }
+    Ok(challenge)
+}
+
+/// Record that a challenge has been matched and drop it from the
+/// open-challenge index, so `list_open_challenges` no longer surfaces it.
+pub(crate) fn mark_matched(env: &Env, challenge: &Challenge) {
+    let mut matched = challenge.clone();
+    matched.status = ChallengeStatus::Matched;
+    env.storage()
+        .temporary()
+        .set(&DataKey::Challenge(matched.id), &matched);
+    remove_from_open_index(env, matched.id);
+}
+
+/// Cancel an open challenge on its creator's behalf, reclaiming the
+/// never-escrowed wager by simply dropping the pending challenge.
+pub(crate) fn cancel_challenge(
+    env: &Env,
+    challenge_id: u32,
+    caller: &Address,
+) -> Result<(), Error> {
+    let challenge: Challenge = env
+        .storage()
+        .temporary()
+        .get(&DataKey::Challenge(challenge_id))
+        .ok_or(Error::ChallengeNotFound)?;
+    if challenge.status != ChallengeStatus::Open {
+        return Err(Error::ChallengeAlreadyMatched);
+    }
+    if &challenge.creator != caller {
+        return Err(Error::NotChallengeCreator);
+    }
+
+    let mut cancelled = challenge;
+    cancelled.status = ChallengeStatus::Cancelled;
+    env.storage()
+        .temporary()
+        .set(&DataKey::Challenge(challenge_id), &cancelled);
+    remove_from_open_index(env, challenge_id);
+    Ok(())
+}
+
+/// A page of still-open challenges, in the order they were created.
+pub(crate) fn list_open_challenges(env: &Env, offset: u32, limit: u32) -> Vec<Challenge> {
+    let open_ids = get_open_challenge_ids(env);
+    let mut page: Vec<Challenge> = Vec::new(env);
+    let end = (offset + limit).min(open_ids.len());
+    let mut i = offset;
+    while i < end {
+        let id = open_ids.get(i).unwrap();
+        if let Some(challenge) = env.storage().temporary().get(&DataKey::Challenge(id)) {
+            page.push_back(challenge);
+        }
+        i += 1;
+    }
+    page
+}
+
+fn get_open_challenge_ids(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OpenChallenges)
+        .unwrap_or(Vec::new(env))
+}
+
+fn remove_from_open_index(env: &Env, challenge_id: u32) {
+    let open_ids = get_open_challenge_ids(env);
+    let mut without: Vec<u32> = Vec::new(env);
+    for i in 0..open_ids.len() {
+        let id = open_ids.get(i).unwrap();
+        if id != challenge_id {
+            without.push_back(id);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::OpenChallenges, &without);
+}